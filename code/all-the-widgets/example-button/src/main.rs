@@ -2,7 +2,8 @@ mod utils;
 
 use std::fmt::{Display, Formatter};
 use iced::widget::{Button, Column, combo_box, ComboBox, Row, Rule, Scrollable, Space, Text};
-use iced::{executor, Application, Command, Element, Settings, Theme, theme};
+use iced::widget::scrollable::{self, Viewport};
+use iced::{executor, Application, Command, Element, Length, Settings, Theme, theme};
 
 pub fn main() -> iced::Result {
   ButtonExample::run(Settings::default())
@@ -12,6 +13,8 @@ pub fn main() -> iced::Result {
 pub enum Message {
   DoNothing,
   Open(String),
+  JumpTo(usize),
+  PageScrolled(Viewport),
   SelectedTheme(ButtonStyle),
 }
 
@@ -34,7 +37,21 @@ impl Display for ButtonStyle {
   }
 }
 
+/// The attributes on this page, in order, for the table of contents. Each one is a utils::section.
+const SECTIONS: [&str; 7] = [
+  "new",
+  "On Press",
+  "On Press Maybe",
+  "Width",
+  "Height",
+  "Padding",
+  "Style",
+];
+
 struct ButtonExample {
+  page_id: scrollable::Id,
+  /// How far the page is scrolled, to highlight the current section.
+  page_offset: f32,
   combo_box_state: combo_box::State<ButtonStyle>,
   selected_theme: ButtonStyle,
 }
@@ -55,6 +72,8 @@ impl Application for ButtonExample {
   fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
     (
       ButtonExample {
+        page_id: scrollable::Id::unique(),
+        page_offset: 0.0,
         combo_box_state: combo_box::State::new(ALL.to_vec()),
         selected_theme: ButtonStyle::Destructive,
       },
@@ -72,6 +91,8 @@ impl Application for ButtonExample {
       Message::Open(url) => {
         opener::open(url).ok();
       }
+      Message::JumpTo(index) => return utils::jump_to(self.page_id.clone(), index),
+      Message::PageScrolled(viewport) => self.page_offset = viewport.absolute_offset().y,
       Message::SelectedTheme(selected_theme) => {
         self.selected_theme = selected_theme;
         self.combo_box_state.unfocus();
@@ -81,65 +102,73 @@ impl Application for ButtonExample {
   }
 
   fn view(&self) -> Element<Message> {
-    Scrollable::new(
+    let page = Scrollable::new(
       Column::with_children(vec![
-        utils::docs_link("Button", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html"))),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.new"))),
-        Button::new("A button").into(),
-        Text::new("Creates a button with a child. The child must implement Into<Element<'a, Message, Renderer>>. Usually you will give it text or a widget.").into(),
-
-        Rule::horizontal(10).into(),
-
-        Text::new("Attributes").size(32).into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("On Press", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.on_press"))),
-        Button::new("A button").on_press(Message::DoNothing).into(),
-        Text::new("Sets the message that will be produced when the Button is pressed. Buttons that do not produce any messages are disabled.").into(),
-
-        utils::docs_link("On Press Maybe", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.on_press_maybe"))),
-        Button::new("A button").on_press_maybe(Some(Message::DoNothing)).into(),
-        Text::new("Sets the message that will be produced when the Button is pressed, if Some.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("Width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.width"))),
-        Button::new("A button").width(100).on_press(Message::DoNothing).into(),
-        Text::new("Sets the width of the Button.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("Height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.height"))),
-        Button::new("A button").height(100).on_press(Message::DoNothing).into(),
-        Text::new("Sets the width of the Button.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("Padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.padding"))),
-        Button::new("A button").padding([5, 10, 15, 20]).on_press(Message::DoNothing).into(),
-        Text::new("Sets the Padding of the Button.").into(),
+        utils::section(vec![
+          utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.new"))),
+          Button::new("A button").into(),
+          Text::new("Creates a button with a child. The child must implement Into<Element<'a, Message, Renderer>>. Usually you will give it text or a widget.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("On Press", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.on_press"))),
+          Button::new("A button").on_press(Message::DoNothing).into(),
+          Text::new("Sets the message that will be produced when the Button is pressed. Buttons that do not produce any messages are disabled.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("On Press Maybe", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.on_press_maybe"))),
+          Button::new("A button").on_press_maybe(Some(Message::DoNothing)).into(),
+          Text::new("Sets the message that will be produced when the Button is pressed, if Some.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("Width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.width"))),
+          Button::new("A button").width(100).on_press(Message::DoNothing).into(),
+          Text::new("Sets the width of the Button.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("Height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.height"))),
+          Button::new("A button").height(100).on_press(Message::DoNothing).into(),
+          Text::new("Sets the width of the Button.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("Padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.padding"))),
+          Button::new("A button").padding([5, 10, 15, 20]).on_press(Message::DoNothing).into(),
+          Text::new("Sets the Padding of the Button.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("Style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.style"))),
+          Button::new("A button").padding([5, 10, 15, 20]).on_press(Message::DoNothing).style(match self.selected_theme {
+            ButtonStyle::Primary => theme::Button::Primary,
+            ButtonStyle::Secondary => theme::Button::Secondary,
+            ButtonStyle::Destructive => theme::Button::Destructive,
+            ButtonStyle::Text => theme::Button::Text,
+          }).into(),
+          Row::with_children(vec![
+            Text::new("Style:").into(),
+            Space::with_width(10).into(),
+            ComboBox::new(&self.combo_box_state, "", Some(&self.selected_theme), Message::SelectedTheme).width(200).into(),
+          ]).into(),
+          Text::new("Sets the style variant of this Button.").into(),
+        ]),
+      ]).padding([0, 100, 100, 100])
+    )
+      .id(self.page_id.clone())
+      .on_scroll(Message::PageScrolled)
+      .height(Length::Fill);
 
+    Column::with_children(vec![
+      Column::with_children(vec![
+        utils::docs_link("Button", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html"))),
+        utils::table_of_contents(&SECTIONS, Some(utils::current_section(self.page_offset, SECTIONS.len())), Message::JumpTo),
         Rule::horizontal(10).into(),
-
-        utils::docs_link("Style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/button/struct.Button.html#method.style"))),
-        Button::new("A button").padding([5, 10, 15, 20]).on_press(Message::DoNothing).style(match self.selected_theme {
-          ButtonStyle::Primary => theme::Button::Primary,
-          ButtonStyle::Secondary => theme::Button::Secondary,
-          ButtonStyle::Destructive => theme::Button::Destructive,
-          ButtonStyle::Text => theme::Button::Text,
-        }).into(),
-        Row::with_children(vec![
-          Text::new("Style:").into(),
-          Space::with_width(10).into(),
-          ComboBox::new(&self.combo_box_state, "", Some(&self.selected_theme), Message::SelectedTheme).width(200).into(),
-        ]).into(),
-        Text::new("Sets the style variant of this Button.").into(),
-      ]).padding([100, 100, 100, 100])
-    ).into()
+      ]).padding([50, 100, 0, 100]).into(),
+      page.into(),
+    ]).into()
   }
 
   fn theme(&self) -> Theme {
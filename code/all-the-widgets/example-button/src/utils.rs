@@ -1,5 +1,6 @@
-use iced::{Element, Renderer, theme};
-use iced::widget::{Button, Text};
+use iced::{Command, Element, Renderer, theme};
+use iced::widget::scrollable::{self, AbsoluteOffset};
+use iced::widget::{Button, Column, Container, Row, Text};
 
 #[derive(Debug, Clone, Copy)]
 struct UrlButtonStyle {}
@@ -45,4 +46,31 @@ pub fn docs_link<'a, Message>(label: &'a str, size: f32, message: Message) -> El
   Element::<Message>::new(Button::<Message, Renderer>::new(Text::new(label).size(size))
     .on_press(message)
     .style(theme::Button::Custom(Box::new(UrlButtonStyle {}))))
+}
+
+/// Every section of a page with a table of contents is this tall, so section n starts n times this far down the page.
+pub const SECTION_HEIGHT: f32 = 450.0;
+
+/// A section of a page with a table of contents. Its children have to fit in SECTION_HEIGHT.
+pub fn section<'a, Message>(children: Vec<Element<'a, Message>>) -> Element<'a, Message> where Message: 'a {
+  Container::new(Column::with_children(children).spacing(10)).height(SECTION_HEIGHT).into()
+}
+
+/// A button for each section, producing on_jump with its index. The current section, if known, is highlighted.
+pub fn table_of_contents<'a, Message>(sections: &[&'a str], current: Option<usize>, on_jump: fn(usize) -> Message) -> Element<'a, Message> where Message: Clone + 'a {
+  Row::with_children(sections.iter().enumerate().map(|(index, name)| {
+    Element::<Message>::new(Button::<Message, Renderer>::new(Text::new(*name).size(16))
+      .on_press(on_jump(index))
+      .style(if current == Some(index) { theme::Button::Primary } else { theme::Button::Text }))
+  }).collect()).spacing(5).into()
+}
+
+/// Scrolls the page with the given Id to the start of a section.
+pub fn jump_to<Message>(page_id: scrollable::Id, index: usize) -> Command<Message> where Message: 'static {
+  scrollable::scroll_to(page_id, AbsoluteOffset { x: 0.0, y: index as f32 * SECTION_HEIGHT })
+}
+
+/// The section at the top of the page, from how far the page is scrolled.
+pub fn current_section(page_offset: f32, count: usize) -> usize {
+  ((page_offset / SECTION_HEIGHT) as usize).min(count - 1)
 }
\ No newline at end of file
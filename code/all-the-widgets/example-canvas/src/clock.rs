@@ -0,0 +1,84 @@
+use chrono::Timelike;
+use iced::mouse;
+use iced::widget::canvas::{self, Cache, Frame, Geometry, LineCap, Path, Stroke};
+use iced::{Point, Rectangle, Renderer, Theme, Vector};
+use std::f32::consts::PI;
+
+/// An analog clock. The face never changes and is cached, the hands are drawn fresh every frame.
+pub struct Clock {
+  now: chrono::DateTime<chrono::Local>,
+  face: Cache,
+}
+
+impl Clock {
+  pub fn new() -> Self {
+    Clock {
+      now: chrono::Local::now(),
+      face: Cache::new(),
+    }
+  }
+
+  /// Doesn't clear the Cache. The face doesn't depend on the time.
+  pub fn tick(&mut self) {
+    self.now = chrono::Local::now();
+  }
+}
+
+impl<Message> canvas::Program<Message> for Clock {
+  type State = ();
+
+  fn draw(&self, _state: &Self::State, renderer: &Renderer, theme: &Theme, bounds: Rectangle, _cursor: mouse::Cursor) -> Vec<Geometry> {
+    let palette = theme.palette();
+    // Frames have their own coordinates, with the origin at the top left of the canvas.
+    let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+    let radius = bounds.width.min(bounds.height) / 2.0 - 5.0;
+
+    let face = self.face.draw(renderer, bounds.size(), |frame| {
+      frame.fill(&Path::circle(center, radius), palette.primary);
+
+      // Rotating the frame is easier than working out where each tick goes.
+      frame.translate(Vector::new(center.x, center.y));
+
+      for hour in 0..12 {
+        frame.with_save(|frame| {
+          frame.rotate(hour as f32 / 12.0 * 2.0 * PI);
+          frame.stroke(
+            &Path::line(
+              Point::new(0.0, -radius * 0.85),
+              Point::new(0.0, -radius * 0.95),
+            ),
+            Stroke::default()
+              .with_color(palette.background)
+              .with_width(3.0),
+          );
+        });
+      }
+    });
+
+    let seconds = self.now.second() as f32 + self.now.nanosecond() as f32 / 1_000_000_000.0;
+    let minutes = self.now.minute() as f32 + seconds / 60.0;
+    let hours = (self.now.hour() % 12) as f32 + minutes / 60.0;
+
+    let mut hands = Frame::new(renderer, bounds.size());
+    hands.translate(Vector::new(center.x, center.y));
+
+    for (turns, length, width) in [
+      (hours / 12.0, 0.5, 6.0),
+      (minutes / 60.0, 0.75, 4.0),
+      (seconds / 60.0, 0.85, 2.0),
+    ] {
+      hands.with_save(|frame| {
+        frame.rotate(turns * 2.0 * PI);
+        frame.stroke(
+          &Path::line(Point::ORIGIN, Point::new(0.0, -radius * length)),
+          Stroke::default()
+            .with_color(palette.background)
+            .with_width(width)
+            .with_line_cap(LineCap::Round),
+        );
+      });
+    }
+
+    vec![face, hands.into_geometry()]
+  }
+}
@@ -0,0 +1,120 @@
+mod clock;
+mod shapes;
+mod sketch;
+mod utils;
+
+use iced::widget::{Button, Canvas, Column, Row, Rule, Scrollable, Slider, Space, Text};
+use iced::{executor, Application, Color, Command, Element, Length, Point, Settings, Subscription, Theme};
+
+extern crate chrono;
+
+pub fn main() -> iced::Result {
+  CanvasExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  SidesChanged(u8),
+  StrokeFinished(Vec<Point>),
+  ClearSketch,
+  Tick,
+}
+
+struct CanvasExample {
+  shapes: shapes::Shapes,
+  sketch: sketch::Sketch,
+  clock: clock::Clock,
+}
+
+impl Application for CanvasExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      CanvasExample {
+        shapes: shapes::Shapes::new(5),
+        sketch: sketch::Sketch::new(),
+        clock: clock::Clock::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Canvas Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::SidesChanged(sides) => self.shapes.set_sides(sides as usize),
+      Message::StrokeFinished(stroke) => self.sketch.add(stroke),
+      Message::ClearSketch => self.sketch.clear(),
+      Message::Tick => self.clock.tick(),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    Scrollable::new(
+      Column::with_children(vec![
+        utils::docs_link("Canvas", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/struct.Canvas.html"))),
+        Text::new("A widget you draw on yourself. It needs the \"canvas\" feature of iced. The drawing is done by a canvas::Program, and each Program on this page lives in its own file.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/struct.Canvas.html#method.new"))),
+        Element::new(Canvas::new(&self.shapes).width(450).height(130)).explain(Color::BLACK),
+        Text::new("Creates a Canvas that draws with a Program. The Program here is kept in the Application and passed by reference, so the Canvas can be rebuilt in every view() without losing anything. width and height set the size of the Canvas, which fills the available space by default.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Program::draw", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/trait.Program.html#tymethod.draw"))),
+        utils::docs_link("Frame", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/struct.Frame.html"))),
+        utils::docs_link("Path", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/struct.Path.html"))),
+        Text::new("draw() returns the Geometry to show. Geometry is made by drawing on a Frame. A Path is a shape: Path::circle, Path::rectangle and Path::line are ready made, Path::new builds anything else point by point. frame.fill paints the inside of a Path, frame.stroke its outline, and frame.fill_text writes text. All positions are relative to the top left corner of the Canvas.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Cache", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/struct.Cache.html"))),
+        Row::with_children(vec![
+          Text::new("Sides:").into(),
+          Space::with_width(10).into(),
+          Slider::new(3..=12, self.shapes.sides() as u8, Message::SidesChanged).width(300).into(),
+        ]).into(),
+        Text::new("draw() is called every frame. A Cache keeps the Geometry from the last time and only runs the drawing closure again after clear(). The shapes above are cached, and moving the slider clears the Cache because the polygon changed. Forget to clear it and the old drawing stays on screen.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Program::update", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/canvas/trait.Program.html#method.update"))),
+        Element::new(Canvas::new(&self.sketch).width(450).height(300)).explain(Color::BLACK),
+        Row::with_children(vec![
+          Text::new(format!("{} strokes", self.sketch.stroke_count())).into(),
+          Space::with_width(20).into(),
+          Button::new("Clear").on_press(Message::ClearSketch).into(),
+        ]).into(),
+        Text::new("Draw on the box with the mouse. update() gets every event over the Canvas and can return a Message. The stroke being drawn is kept in the Program's State, which Iced stores for us, so the Application only hears about a stroke once it is finished. Finished strokes are cached, the one being drawn is not. mouse_interaction turns the cursor into a crosshair.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Animation").size(32).into(),
+        Canvas::new(&self.clock).width(200).height(200).into(),
+        Text::new("The time from basic-state-1, drawn as a clock and updated by a timer Subscription. A Program can return several Geometries. The face is cached because it never changes, while the hands are drawn on a new Frame every time.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    iced::time::every(std::time::Duration::from_millis(100)).map(|_| Message::Tick)
+  }
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use iced::alignment::Horizontal;
+use iced::mouse;
+use iced::widget::canvas::{self, Cache, Geometry, LineJoin, Path, Stroke};
+use iced::{Point, Rectangle, Renderer, Size, Theme};
+use std::f32::consts::PI;
+
+/// A few shapes that only change when the number of sides does.
+pub struct Shapes {
+  sides: usize,
+  cache: Cache,
+}
+
+impl Shapes {
+  pub fn new(sides: usize) -> Self {
+    Shapes {
+      sides,
+      cache: Cache::new(),
+    }
+  }
+
+  pub fn sides(&self) -> usize {
+    self.sides
+  }
+
+  /// The Cache can't tell that the drawing is out of date. Anything it depends on has to clear the Cache when it
+  /// changes.
+  pub fn set_sides(&mut self, sides: usize) {
+    self.sides = sides;
+    self.cache.clear();
+  }
+}
+
+impl<Message> canvas::Program<Message> for Shapes {
+  type State = ();
+
+  fn draw(&self, _state: &Self::State, renderer: &Renderer, theme: &Theme, bounds: Rectangle, _cursor: mouse::Cursor) -> Vec<Geometry> {
+    let geometry = self.cache.draw(renderer, bounds.size(), |frame| {
+      let palette = theme.palette();
+
+      frame.fill_rectangle(
+        Point::new(10.0, 10.0),
+        Size::new(120.0, 80.0),
+        palette.primary,
+      );
+
+      frame.stroke(
+        &Path::circle(Point::new(220.0, 50.0), 40.0),
+        Stroke::default().with_color(palette.danger).with_width(4.0),
+      );
+
+      // Any shape can be built point by point.
+      let center = Point::new(360.0, 50.0);
+      let polygon = Path::new(|builder| {
+        for side in 0..self.sides {
+          let angle = side as f32 / self.sides as f32 * 2.0 * PI - PI / 2.0;
+          let point = Point::new(center.x + 40.0 * angle.cos(), center.y + 40.0 * angle.sin());
+
+          if side == 0 {
+            builder.move_to(point);
+          } else {
+            builder.line_to(point);
+          }
+        }
+
+        builder.close();
+      });
+
+      frame.fill(&polygon, palette.success);
+      frame.stroke(
+        &polygon,
+        Stroke::default()
+          .with_color(palette.text)
+          .with_width(3.0)
+          .with_line_join(LineJoin::Round),
+      );
+
+      frame.fill_text(canvas::Text {
+        content: format!("{} sides", self.sides),
+        position: Point::new(center.x, 105.0),
+        color: palette.text,
+        size: 16.0,
+        horizontal_alignment: Horizontal::Center,
+        ..canvas::Text::default()
+      });
+    });
+
+    vec![geometry]
+  }
+}
@@ -0,0 +1,123 @@
+use crate::Message;
+use iced::mouse;
+use iced::widget::canvas::{self, event, Cache, Event, Frame, Geometry, LineCap, LineJoin, Path, Stroke};
+use iced::{Color, Point, Rectangle, Renderer, Theme};
+
+/// A canvas to draw lines on with the mouse.
+pub struct Sketch {
+  strokes: Vec<Vec<Point>>,
+  cache: Cache,
+}
+
+/// State that belongs to the canvas rather than the Application. Iced keeps it between frames for us.
+#[derive(Default)]
+pub struct State {
+  /// The stroke being drawn right now, if the mouse button is down.
+  current: Option<Vec<Point>>,
+}
+
+impl Sketch {
+  pub fn new() -> Self {
+    Sketch {
+      strokes: Vec::new(),
+      cache: Cache::new(),
+    }
+  }
+
+  pub fn stroke_count(&self) -> usize {
+    self.strokes.len()
+  }
+
+  pub fn add(&mut self, stroke: Vec<Point>) {
+    self.strokes.push(stroke);
+    self.cache.clear();
+  }
+
+  pub fn clear(&mut self) {
+    self.strokes.clear();
+    self.cache.clear();
+  }
+}
+
+impl canvas::Program<Message> for Sketch {
+  type State = State;
+
+  fn update(&self, state: &mut Self::State, event: Event, bounds: Rectangle, cursor: mouse::Cursor) -> (event::Status, Option<Message>) {
+    match event {
+      Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+        if let Some(position) = cursor.position_in(bounds) {
+          state.current = Some(vec![position]);
+          return (event::Status::Captured, None);
+        }
+      }
+      Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+        if let (Some(current), Some(position)) = (&mut state.current, cursor.position_in(bounds)) {
+          current.push(position);
+          return (event::Status::Captured, None);
+        }
+      }
+      // The button can be let go outside of the canvas. The stroke still has to end.
+      Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+        if let Some(stroke) = state.current.take() {
+          return (
+            event::Status::Captured,
+            Some(Message::StrokeFinished(stroke)),
+          );
+        }
+      }
+      _ => {}
+    }
+
+    (event::Status::Ignored, None)
+  }
+
+  fn draw(&self, state: &Self::State, renderer: &Renderer, theme: &Theme, bounds: Rectangle, _cursor: mouse::Cursor) -> Vec<Geometry> {
+    let stroke = Stroke::default()
+      .with_color(theme.palette().text)
+      .with_width(3.0)
+      .with_line_cap(LineCap::Round)
+      .with_line_join(LineJoin::Round);
+
+    // Finished strokes only change through add() and clear(), which clear the Cache.
+    let finished = self.cache.draw(renderer, bounds.size(), |frame| {
+      frame.fill_rectangle(
+        Point::ORIGIN,
+        bounds.size(),
+        Color::from_rgb(0.95, 0.95, 0.95),
+      );
+
+      for points in &self.strokes {
+        frame.stroke(&line(points), stroke.clone());
+      }
+    });
+
+    // The stroke being drawn changes on every mouse move, so caching it would not help.
+    let mut frame = Frame::new(renderer, bounds.size());
+
+    if let Some(points) = &state.current {
+      frame.stroke(&line(points), stroke.with_color(theme.palette().primary));
+    }
+
+    vec![finished, frame.into_geometry()]
+  }
+
+  fn mouse_interaction(&self, _state: &Self::State, bounds: Rectangle, cursor: mouse::Cursor) -> mouse::Interaction {
+    if cursor.is_over(bounds) {
+      mouse::Interaction::Crosshair
+    } else {
+      mouse::Interaction::default()
+    }
+  }
+}
+
+fn line(points: &[Point]) -> Path {
+  Path::new(|builder| {
+    if let Some((first, rest)) = points.split_first() {
+      builder.move_to(*first);
+
+      for point in rest {
+        builder.line_to(*point);
+      }
+    }
+  })
+}
@@ -1,7 +1,8 @@
 mod utils;
 
-use iced::widget::{Column, Rule, Scrollable, Text};
-use iced::{executor, Application, Command, Element, Settings, Theme, Color, Alignment};
+use iced::widget::{Column, Row, Rule, Scrollable, Text};
+use iced::widget::scrollable::{self, Viewport};
+use iced::{executor, Application, Command, Element, Length, Settings, Theme, Color, Alignment};
 
 pub fn main() -> iced::Result {
   ColumnExample::run(Settings::default())
@@ -10,9 +11,28 @@ pub fn main() -> iced::Result {
 #[derive(Debug, Clone)]
 pub enum Message {
   Open(String),
+  JumpTo(usize),
+  PageScrolled(Viewport),
 }
 
-struct ColumnExample {}
+/// The attributes on this page, in order, for the table of contents. Each one is a utils::section.
+const SECTIONS: [&str; 9] = [
+  "new",
+  "with_children",
+  "push",
+  "width",
+  "height",
+  "max_width",
+  "padding",
+  "spacing",
+  "align_items",
+];
+
+struct ColumnExample {
+  page_id: scrollable::Id,
+  /// How far the page is scrolled, to highlight the current section.
+  page_offset: f32,
+}
 
 impl Application for ColumnExample {
   type Executor = executor::Default;
@@ -23,6 +43,8 @@ impl Application for ColumnExample {
   fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
     (
       ColumnExample {
+        page_id: scrollable::Id::unique(),
+        page_offset: 0.0,
       },
       Command::none(),
     )
@@ -37,114 +59,128 @@ impl Application for ColumnExample {
       Message::Open(url) => {
         opener::open(url).ok();
       }
+      Message::JumpTo(index) => return utils::jump_to(self.page_id.clone(), index),
+      Message::PageScrolled(viewport) => self.page_offset = viewport.absolute_offset().y,
     }
     Command::none()
   }
 
   fn view(&self) -> Element<Message> {
-    Scrollable::new(
+    let page = Scrollable::new(
+      Column::with_children(vec![
+        utils::section(vec![
+          utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.new"))),
+          Element::new(Column::new()).explain(Color::BLACK),
+          Text::new("Creates an empty Column. By default width and height will try to fit its children. An empty column will be 0 by 0.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.with_children"))),
+          Element::new(Column::with_children(vec![
+            Text::new("Child 1").into(),
+            Text::new("Child 2").into(),
+            Text::new("Child 3").into(),
+          ])).explain(Color::BLACK),
+          Text::new("Creates a Column with the given elements.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("push", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.push"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            ).explain(Color::BLACK),
+          Text::new("Adds an element to the Column.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.width"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .width(100)).explain(Color::BLACK),
+          Text::new("Sets the width of the Column.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.height"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .height(100)).explain(Color::BLACK),
+          Text::new("Sets the height of the Column.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("max_width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.max_width"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 22222222222222222222222222222222222222222"))
+            .push(Text::new("Child 3"))
+            .max_width(100)).explain(Color::BLACK),
+          Text::new("Sets the maximum width of the Column.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.padding"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 22222222222222222222222222222222222222222"))
+            .push(Text::new("Child 3"))
+            .padding([5, 10, 15, 20])).explain(Color::BLACK),
+          Text::new("Sets the Padding of the Column.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.spacing"))),
+          Element::new(Column::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 22222222222222222222222222222222222222222"))
+            .push(Text::new("Child 3"))
+            .spacing(20)).explain(Color::BLACK),
+          Text::new("Sets the vertical spacing between elements.").into(),
+        ]),
+
+        // Side by side, so the section stays within utils::SECTION_HEIGHT.
+        utils::section(vec![
+          utils::docs_link("align_items", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.align_items"))),
+          Row::with_children(vec![
+            Element::new(Column::new()
+              .push(Text::new("Alignment::Start"))
+              .push(Text::new("Alignment::Start"))
+              .push(Text::new("Alignment::Start"))
+              .align_items(Alignment::Start).width(200).height(300)).explain(Color::BLACK),
+            Element::new(Column::new()
+              .push(Text::new("Alignment::Center"))
+              .push(Text::new("Alignment::Center"))
+              .push(Text::new("Alignment::Center"))
+              .align_items(Alignment::Center).width(200).height(300)).explain(Color::BLACK),
+            Element::new(Column::new()
+              .push(Text::new("Alignment::End"))
+              .push(Text::new("Alignment::End"))
+              .push(Text::new("Alignment::End"))
+              .align_items(Alignment::End).width(200).height(300)).explain(Color::BLACK),
+          ]).spacing(20).into(),
+          Text::new("Sets the horizontal alignment of the contents of the Column.").into(),
+        ]),
+      ]).padding([0, 100, 100, 100])
+    )
+      .id(self.page_id.clone())
+      .on_scroll(Message::PageScrolled)
+      .height(Length::Fill);
+
+    Column::with_children(vec![
       Column::with_children(vec![
         utils::docs_link("Column", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html"))),
         Text::new("A container that distributes its contents vertically.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.new"))),
-        Element::new(Column::new()).explain(Color::BLACK),
-        Text::new("Creates an empty Column. By default width and height will try to fit its children. An empty column will be 0 by 0.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.with_children"))),
-        Element::new(Column::with_children(vec![
-          Text::new("Child 1").into(),
-          Text::new("Child 2").into(),
-          Text::new("Child 3").into(),
-        ])).explain(Color::BLACK),
-        Text::new("Creates a Column with the given elements.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.push"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          ).explain(Color::BLACK),
-        Text::new("Adds an element to the Column.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.width"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .width(100)).explain(Color::BLACK),
-        Text::new("Sets the width of the Column.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.height"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .height(100)).explain(Color::BLACK),
-        Text::new("Sets the height of the Column.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("max_width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.max_width"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 22222222222222222222222222222222222222222"))
-          .push(Text::new("Child 3"))
-          .max_width(100)).explain(Color::BLACK),
-        Text::new("Sets the maximum width of the Column.").into(),
-
+        utils::table_of_contents(&SECTIONS, Some(utils::current_section(self.page_offset, SECTIONS.len())), Message::JumpTo),
         Rule::horizontal(10).into(),
-
-        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.padding"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 22222222222222222222222222222222222222222"))
-          .push(Text::new("Child 3"))
-          .padding([5, 10, 15, 20])).explain(Color::BLACK),
-        Text::new("Sets the Padding of the Column.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.spacing"))),
-        Element::new(Column::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 22222222222222222222222222222222222222222"))
-          .push(Text::new("Child 3"))
-          .spacing(20)).explain(Color::BLACK),
-        Text::new("Sets the vertical spacing between elements.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("align_items", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Column.html#method.align_items"))),
-        Element::new(Column::new()
-          .push(Text::new("Alignment::Start"))
-          .push(Text::new("Alignment::Start"))
-          .push(Text::new("Alignment::Start"))
-          .align_items(Alignment::Start).width(300).height(300)).explain(Color::BLACK),
-        Element::new(Column::new()
-          .push(Text::new("Alignment::Center"))
-          .push(Text::new("Alignment::Center"))
-          .push(Text::new("Alignment::Center"))
-          .align_items(Alignment::Center).width(300).height(300)).explain(Color::BLACK),
-        Element::new(Column::new()
-          .push(Text::new("Alignment::End"))
-          .push(Text::new("Alignment::End"))
-          .push(Text::new("Alignment::End"))
-          .align_items(Alignment::End).width(300).height(300)).explain(Color::BLACK),
-        Text::new("Sets the horizontal alignment of the contents of the Column.").into(),
-
-      ]).padding([100, 100, 100, 100])
-    ).into()
+      ]).padding([50, 100, 0, 100]).into(),
+      page.into(),
+    ]).into()
   }
 
   fn theme(&self) -> Theme {
@@ -1,7 +1,8 @@
 mod utils;
 
 use iced::widget::{Column, Container, Row, Rule, Scrollable, Text};
-use iced::{executor, Application, Command, Element, Settings, Theme, Color};
+use iced::widget::scrollable::{self, Viewport};
+use iced::{executor, Application, Command, Element, Length, Settings, Theme, Color};
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::container::Id;
 
@@ -12,9 +13,30 @@ pub fn main() -> iced::Result {
 #[derive(Debug, Clone)]
 pub enum Message {
   Open(String),
+  JumpTo(usize),
+  PageScrolled(Viewport),
 }
 
-struct ContainerExample {}
+/// The attributes on this page, in order, for the table of contents. Each one is a utils::section.
+const SECTIONS: [&str; 11] = [
+  "new",
+  "width",
+  "height",
+  "max_width",
+  "max_height",
+  "padding",
+  "align_x",
+  "align_y",
+  "center_x",
+  "center_y",
+  "id",
+];
+
+struct ContainerExample {
+  page_id: scrollable::Id,
+  /// How far the page is scrolled, to highlight the current section.
+  page_offset: f32,
+}
 
 impl Application for ContainerExample {
   type Executor = executor::Default;
@@ -25,6 +47,8 @@ impl Application for ContainerExample {
   fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
     (
       ContainerExample {
+        page_id: scrollable::Id::unique(),
+        page_offset: 0.0,
       },
       Command::none()
     )
@@ -39,90 +63,101 @@ impl Application for ContainerExample {
       Message::Open(url) => {
         opener::open(url).ok();
       }
+      Message::JumpTo(index) => return utils::jump_to(self.page_id.clone(), index),
+      Message::PageScrolled(viewport) => self.page_offset = viewport.absolute_offset().y,
     }
     Command::none()
   }
 
   fn view(&self) -> Element<Message> {
-    Scrollable::new(
+    let page = Scrollable::new(
+      Column::with_children(vec![
+        utils::section(vec![
+          utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.new"))),
+          Element::new(Row::new()).explain(Color::BLACK),
+          Text::new("Creates an empty Row. By default width and height will try to fit its children. An empty Row will be 0 by 0.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.width"))),
+          Element::new(Container::new(Text::new("Container")).width(50)).explain(Color::BLACK),
+          Text::new("Sets the width of the Container.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.height"))),
+          Element::new(Container::new(Text::new("Container")).height(50)).explain(Color::BLACK),
+          Text::new("Sets the height of the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("max_width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.max_width"))),
+          Element::new(Container::new(Text::new("Fooooooooooooooooooooooooooooooooooooooo")).max_width(50)).explain(Color::BLACK),
+          Text::new("Sets the maximum width of the Container.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("max_height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.max_height"))),
+          Element::new(Container::new(Container::new(Text::new("Container")).height(100)).max_height(50)).explain(Color::BLACK),
+          Text::new("Sets the maximum height of the Container.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.padding"))),
+          Element::new(Container::new(Text::new("Container"))
+            .padding([5, 10, 15, 20])).explain(Color::BLACK),
+          Text::new("Sets the Padding of the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("align_x", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.align_x"))),
+          Element::new(Container::new(Text::new("Horizontal::Left")).align_x(Horizontal::Left).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Container::new(Text::new("Horizontal::Center")).align_x(Horizontal::Center).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Container::new(Text::new("Horizontal::Right")).align_x(Horizontal::Right).width(600).height(100)).explain(Color::BLACK),
+          Text::new("Sets the content alignment for the horizontal axis of the Container.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("align_y", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.align_y"))),
+          Element::new(Container::new(Text::new("Vertical::Left")).align_y(Vertical::Top).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Container::new(Text::new("Vertical::Center")).align_y(Vertical::Center).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Container::new(Text::new("Vertical::Bottom")).align_y(Vertical::Bottom).width(600).height(100)).explain(Color::BLACK),
+          Text::new("Sets the content alignment for the vertical axis of the Container.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("center_x", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.center_x"))),
+          Element::new(Container::new(Text::new("center_x")).center_x().width(600).height(100)).explain(Color::BLACK),
+          Text::new("A shortcut for .align(Horizontal::Center).").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("center_y", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.center_y"))),
+          Element::new(Container::new(Text::new("center_y")).center_y().width(600).height(100)).explain(Color::BLACK),
+          Text::new("A shortcut for .align_y(Vertical::Center).").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("id", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.id"))),
+          Element::new(Container::new(Text::new("Container")).id(Id::new("a"))).explain(Color::BLACK),
+          Element::new(Container::new(Text::new("Container")).id(Id::new("a"))).explain(Color::BLACK),
+          Text::new("A shortcut for .align_y(Vertical::Center).").into(),
+        ]),
+      ]).padding([0, 100, 100, 100])
+    )
+      .id(self.page_id.clone())
+      .on_scroll(Message::PageScrolled)
+      .height(Length::Fill);
+
+    Column::with_children(vec![
       Column::with_children(vec![
         utils::docs_link("Container", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html"))),
         Text::new("An element decorating some content. It is normally used for alignment purposes.").into(),
-
+        utils::table_of_contents(&SECTIONS, Some(utils::current_section(self.page_offset, SECTIONS.len())), Message::JumpTo),
         Rule::horizontal(10).into(),
-
-        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.new"))),
-        Element::new(Row::new()).explain(Color::BLACK),
-        Text::new("Creates an empty Row. By default width and height will try to fit its children. An empty Row will be 0 by 0.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.width"))),
-        Element::new(Container::new(Text::new("Container")).width(50)).explain(Color::BLACK),
-        Text::new("Sets the width of the Container.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.height"))),
-        Element::new(Container::new(Text::new("Container")).height(50)).explain(Color::BLACK),
-        Text::new("Sets the height of the Row.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("max_width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.max_width"))),
-        Element::new(Container::new(Text::new("Fooooooooooooooooooooooooooooooooooooooo")).max_width(50)).explain(Color::BLACK),
-        Text::new("Sets the maximum width of the Container.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("max_height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.max_height"))),
-        Element::new(Container::new(Container::new(Text::new("Container")).height(100)).max_height(50)).explain(Color::BLACK),
-        Text::new("Sets the maximum height of the Container.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.padding"))),
-        Element::new(Container::new(Text::new("Container"))
-          .padding([5, 10, 15, 20])).explain(Color::BLACK),
-        Text::new("Sets the Padding of the Row.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("align_x", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.align_x"))),
-        Element::new(Container::new(Text::new("Horizontal::Left")).align_x(Horizontal::Left).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Container::new(Text::new("Horizontal::Center")).align_x(Horizontal::Center).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Container::new(Text::new("Horizontal::Right")).align_x(Horizontal::Right).width(600).height(100)).explain(Color::BLACK),
-        Text::new("Sets the content alignment for the horizontal axis of the Container.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("align_y", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.align_y"))),
-        Element::new(Container::new(Text::new("Vertical::Left")).align_y(Vertical::Top).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Container::new(Text::new("Vertical::Center")).align_y(Vertical::Center).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Container::new(Text::new("Vertical::Bottom")).align_y(Vertical::Bottom).width(600).height(100)).explain(Color::BLACK),
-        Text::new("Sets the content alignment for the vertical axis of the Container.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("center_x", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.center_x"))),
-        Element::new(Container::new(Text::new("center_x")).center_x().width(600).height(100)).explain(Color::BLACK),
-        Text::new("A shortcut for .align(Horizontal::Center).").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("center_y", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.center_y"))),
-        Element::new(Container::new(Text::new("center_y")).center_y().width(600).height(100)).explain(Color::BLACK),
-        Text::new("A shortcut for .align_y(Vertical::Center).").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("id", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Container.html#method.id"))),
-        Element::new(Container::new(Text::new("Container")).id(Id::new("a"))).explain(Color::BLACK),
-        Element::new(Container::new(Text::new("Container")).id(Id::new("a"))).explain(Color::BLACK),
-        Text::new("A shortcut for .align_y(Vertical::Center).").into(),
-
-      ]).padding([100, 100, 100, 100])
-    ).into()
+      ]).padding([50, 100, 0, 100]).into(),
+      page.into(),
+    ]).into()
   }
 
   fn theme(&self) -> Theme {
@@ -0,0 +1,219 @@
+mod utils;
+
+use iced::widget::{image, svg, Column, Image, Row, Rule, Scrollable, Space, Svg, Text};
+use iced::{executor, theme, Application, Color, Command, ContentFit, Element, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  ImageExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+}
+
+/// Paths are relative to the crate, so the example works no matter where it is started from.
+fn asset(name: &str) -> String {
+  format!("{}/assets/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+const CONTENT_FITS: [(ContentFit, &str); 5] = [
+  (ContentFit::Contain, "Contain"),
+  (ContentFit::Cover, "Cover"),
+  (ContentFit::Fill, "Fill"),
+  (ContentFit::None, "None"),
+  (ContentFit::ScaleDown, "ScaleDown"),
+];
+
+/// Draws every part of the SVG in one color.
+struct SvgColor {
+  color: Color,
+}
+
+impl svg::StyleSheet for SvgColor {
+  type Style = Theme;
+
+  fn appearance(&self, _style: &Self::Style) -> svg::Appearance {
+    svg::Appearance {
+      color: Some(self.color),
+    }
+  }
+}
+
+/// Like SvgColor, but the color comes from the Theme, so it follows light and dark mode.
+struct SvgPrimary {}
+
+impl svg::StyleSheet for SvgPrimary {
+  type Style = Theme;
+
+  fn appearance(&self, style: &Self::Style) -> svg::Appearance {
+    svg::Appearance {
+      color: Some(style.palette().primary),
+    }
+  }
+}
+
+struct ImageExample {
+  /// Handles are created once and cloned into view(). A clone is cheap and keeps the same id, so the renderer
+  /// decodes the image once and reuses it.
+  landscape: image::Handle,
+  landscape_from_memory: image::Handle,
+  checkerboard: image::Handle,
+  star: svg::Handle,
+}
+
+impl Application for ImageExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      ImageExample {
+        landscape: image::Handle::from_path(asset("landscape.png")),
+        landscape_from_memory: image::Handle::from_memory(include_bytes!("../assets/landscape.png").as_slice()),
+        checkerboard: image::Handle::from_path(asset("checkerboard.png")),
+        star: svg::Handle::from_path(asset("star.svg")),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Image Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let image_fits = Row::with_children(CONTENT_FITS.iter().map(|(content_fit, name)| {
+      Column::with_children(vec![
+        Element::new(Image::new(self.landscape.clone()).width(150).height(150).content_fit(*content_fit)).explain(Color::BLACK),
+        Text::new(*name).into(),
+      ]).spacing(5).into()
+    }).collect()).spacing(20);
+
+    let svg_fits = Row::with_children(CONTENT_FITS.iter().map(|(content_fit, name)| {
+      Column::with_children(vec![
+        Element::new(Svg::new(self.star.clone()).width(150).height(100).content_fit(*content_fit)).explain(Color::BLACK),
+        Text::new(*name).into(),
+      ]).spacing(5).into()
+    }).collect()).spacing(20);
+
+    Scrollable::new(
+      Column::with_children(vec![
+        Text::new("Image and Svg").size(48).into(),
+        Text::new("Widgets that show pictures. They need the \"image\" and \"svg\" features of iced. The files used here are in this crate's assets folder.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Image", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Image.html"))),
+        Text::new("Shows a raster image, like a PNG or a JPEG.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Image.html#method.new"))),
+        Image::new(self.landscape.clone()).into(),
+        Text::new("Creates an Image from a Handle. Anything that turns into a Handle works too, like a path. By default the Image is as big as the picture.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Handle::from_path", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Handle.html#method.from_path"))),
+        utils::docs_link("Handle::from_memory", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Handle.html#method.from_memory"))),
+        Row::with_children(vec![
+          Image::new(self.landscape.clone()).into(),
+          Space::with_width(20).into(),
+          Image::new(self.landscape_from_memory.clone()).into(),
+        ]).into(),
+        Text::new("A Handle is where the picture comes from. from_path reads a file when the image is first drawn. from_memory takes bytes you already have, here the same file built into the binary with include_bytes!, so it can't go missing.").into(),
+        Text::new("Keep Handles in your state and clone them in view(). A Handle's id is what the renderer caches the decoded image by. For from_memory that id is a hash of all the bytes, so making a new Handle in every view() would hash the whole file every time.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("A missing file").size(24).into(),
+        Element::new(Image::new(image::Handle::from_path(asset("missing.png"))).width(150).height(150)).explain(Color::BLACK),
+        Text::new("A Handle to a file that doesn't exist is not an error when it is created. The file is only read when it is drawn, and an image that fails to load is simply not drawn. If you need to know, check the file yourself before making the Handle.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Image.html#method.width"))),
+        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Image.html#method.height"))),
+        Row::with_children(vec![
+          Element::new(Image::new(self.landscape.clone()).width(100)).explain(Color::BLACK),
+          Space::with_width(20).into(),
+          Element::new(Image::new(self.landscape.clone()).height(50)).explain(Color::BLACK),
+          Space::with_width(20).into(),
+          Element::new(Image::new(self.landscape.clone()).width(300).height(100)).explain(Color::BLACK),
+        ]).into(),
+        Text::new("Sets the size of the Image. The picture keeps its aspect ratio inside that size, unless content_fit says otherwise.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("content_fit", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/image/struct.Image.html#method.content_fit"))),
+        image_fits.into(),
+        Text::new("Sets how the picture fills a box of a different shape. Contain shows all of it, Cover fills the box and crops, Fill stretches, None keeps the original size and crops, and ScaleDown is Contain that never makes it bigger.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Scaling").size(24).into(),
+        Row::with_children(vec![
+          Image::new(self.checkerboard.clone()).into(),
+          Space::with_width(20).into(),
+          Image::new(self.checkerboard.clone()).width(160).height(160).content_fit(ContentFit::Fill).into(),
+        ]).into(),
+        Text::new("An 8 by 8 pixel image shown at its own size and blown up 20 times. Scaled images are smoothed, which blurs pixel art. The version of Iced used by this book has no way to change that. Later versions add Image::filter_method with a Nearest option for sharp pixels.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Svg", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/svg/struct.Svg.html"))),
+        Text::new("Shows a vector image. It stays sharp at any size, which makes it a good fit for icons.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/svg/struct.Svg.html#method.new"))),
+        Row::with_children(vec![
+          Svg::new(self.star.clone()).width(24).height(24).into(),
+          Space::with_width(20).into(),
+          Svg::new(self.star.clone()).width(48).height(48).into(),
+          Space::with_width(20).into(),
+          Svg::new(self.star.clone()).width(96).height(96).into(),
+        ]).into(),
+        Text::new("Creates an Svg from an svg::Handle, made with from_path or from_memory like an image Handle. An Svg fills the available width by default, so it usually needs a size.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("content_fit", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/svg/struct.Svg.html#method.content_fit"))),
+        svg_fits.into(),
+        Text::new("Works the same way as content_fit on Image.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/svg/struct.Svg.html#method.style"))),
+        Row::with_children(vec![
+          Svg::new(self.star.clone()).width(48).height(48).style(theme::Svg::Default).into(),
+          Space::with_width(20).into(),
+          Svg::new(self.star.clone()).width(48).height(48).style(theme::Svg::Custom(Box::new(SvgColor { color: Color::from_rgb(0.8, 0.2, 0.2) }))).into(),
+          Space::with_width(20).into(),
+          Svg::new(self.star.clone()).width(48).height(48).style(theme::Svg::Custom(Box::new(SvgPrimary {}))).into(),
+        ]).into(),
+        Text::new("Sets the style of the Svg. A style can paint the whole picture in one color, which is how one icon file can match any Theme. From left to right: the original colors, a fixed red, and the Theme's primary color.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
\ No newline at end of file
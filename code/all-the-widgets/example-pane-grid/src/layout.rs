@@ -0,0 +1,70 @@
+use crate::PaneContent;
+use iced::widget::pane_grid::{self, Axis, Configuration, Node};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The pane_grid types can't be serialized, so the layout is copied into this tree first.
+#[derive(Debug, Serialize, Deserialize)]
+enum Layout {
+  Split {
+    is_vertical: bool,
+    ratio: f32,
+    a: Box<Layout>,
+    b: Box<Layout>,
+  },
+  Pane(PaneContent),
+}
+
+pub fn save(path: &Path, panes: &pane_grid::State<PaneContent>) -> Result<(), String> {
+  let layout = from_node(panes.layout(), panes);
+  let contents = serde_json::to_string_pretty(&layout).map_err(|error| error.to_string())?;
+
+  std::fs::write(path, contents).map_err(|error| format!("Can't write {}: {}", path.display(), error))
+}
+
+pub fn load(path: &Path) -> Result<Configuration<PaneContent>, String> {
+  let contents = std::fs::read_to_string(path).map_err(|error| format!("Can't read {}: {}", path.display(), error))?;
+  let layout = serde_json::from_str(&contents).map_err(|error| format!("{} is not valid: {}", path.display(), error))?;
+
+  Ok(to_configuration(layout))
+}
+
+fn from_node(node: &Node, panes: &pane_grid::State<PaneContent>) -> Layout {
+  match node {
+    Node::Split {
+      axis, ratio, a, b, ..
+    } => Layout::Split {
+      is_vertical: *axis == Axis::Vertical,
+      ratio: *ratio,
+      a: Box::new(from_node(a, panes)),
+      b: Box::new(from_node(b, panes)),
+    },
+    // Every Pane in the layout is in the State, so get can't fail here.
+    Node::Pane(pane) => Layout::Pane(
+      *panes
+        .get(pane)
+        .expect("The layout only contains panes of the State"),
+    ),
+  }
+}
+
+fn to_configuration(layout: Layout) -> Configuration<PaneContent> {
+  match layout {
+    Layout::Split {
+      is_vertical,
+      ratio,
+      a,
+      b,
+    } => Configuration::Split {
+      axis: if is_vertical {
+        Axis::Vertical
+      } else {
+        Axis::Horizontal
+      },
+      ratio,
+      a: Box::new(to_configuration(*a)),
+      b: Box::new(to_configuration(*b)),
+    },
+    Layout::Pane(content) => Configuration::Pane(content),
+  }
+}
@@ -0,0 +1,238 @@
+mod layout;
+mod utils;
+
+use iced::widget::pane_grid::{self, Axis, Pane, PaneGrid};
+use iced::widget::{container, Button, Column, Container, Row, Rule, Scrollable, Space, Text};
+use iced::{executor, theme, Application, Color, Command, Element, Length, Settings, Theme};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub fn main() -> iced::Result {
+  PaneGridExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  Split(Axis, Pane),
+  Clicked(Pane),
+  Dragged(pane_grid::DragEvent),
+  Resized(pane_grid::ResizeEvent),
+  Maximize(Pane),
+  Restore,
+  Close(Pane),
+  SaveLayout,
+  LoadLayout,
+}
+
+/// What each pane holds. A real app would keep a file, a terminal or a panel here.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PaneContent {
+  id: usize,
+}
+
+/// A border that shows which pane is focused.
+struct PaneStyle {
+  is_focused: bool,
+}
+
+impl container::StyleSheet for PaneStyle {
+  type Style = Theme;
+
+  fn appearance(&self, style: &Self::Style) -> container::Appearance {
+    let palette = style.extended_palette();
+
+    container::Appearance {
+      background: Some(palette.background.base.color.into()),
+      border_width: 2.0,
+      border_color: if self.is_focused { palette.primary.strong.color } else { palette.background.strong.color },
+      ..Default::default()
+    }
+  }
+}
+
+struct PaneGridExample {
+  panes: pane_grid::State<PaneContent>,
+  focus: Option<Pane>,
+  /// The id the next new pane gets, so every pane has a different number.
+  next_id: usize,
+  layout_path: PathBuf,
+  layout_status: String,
+}
+
+impl Application for PaneGridExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    let (panes, first) = pane_grid::State::new(PaneContent { id: 1 });
+
+    (
+      PaneGridExample {
+        panes,
+        focus: Some(first),
+        next_id: 2,
+        layout_path: PathBuf::from("pane-grid-layout.json"),
+        layout_status: String::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Pane Grid Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::Split(axis, pane) => {
+        if let Some((new_pane, _split)) = self.panes.split(axis, &pane, PaneContent { id: self.next_id }) {
+          self.focus = Some(new_pane);
+          self.next_id += 1;
+        }
+      }
+      Message::Clicked(pane) => self.focus = Some(pane),
+      Message::Dragged(pane_grid::DragEvent::Dropped { pane, target }) => self.panes.swap(&pane, &target),
+      Message::Dragged(_) => {}
+      Message::Resized(pane_grid::ResizeEvent { split, ratio }) => self.panes.resize(&split, ratio),
+      Message::Maximize(pane) => self.panes.maximize(&pane),
+      Message::Restore => self.panes.restore(),
+      Message::Close(pane) => {
+        // close gives back a sibling of the closed pane, a good place for the focus to go.
+        if let Some((_content, sibling)) = self.panes.close(&pane) {
+          self.focus = Some(sibling);
+        }
+      }
+      Message::SaveLayout => {
+        self.layout_status = match layout::save(&self.layout_path, &self.panes) {
+          Ok(()) => format!("Saved to {}", self.layout_path.display()),
+          Err(error) => error,
+        };
+      }
+      Message::LoadLayout => {
+        self.layout_status = match layout::load(&self.layout_path) {
+          Ok(configuration) => {
+            self.panes = pane_grid::State::with_configuration(configuration);
+            self.focus = None;
+            self.next_id = self.panes.iter().map(|(_, content)| content.id).max().unwrap_or(0) + 1;
+            format!("Loaded from {}", self.layout_path.display())
+          }
+          Err(error) => error,
+        };
+      }
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let pane_count = self.panes.len();
+
+    let pane_grid = PaneGrid::new(&self.panes, |pane, content, is_maximized| {
+      let is_focused = self.focus == Some(pane);
+
+      let maximize = if is_maximized {
+        Button::new(Text::new("Restore").size(14)).on_press(Message::Restore)
+      } else {
+        Button::new(Text::new("Maximize").size(14)).on_press(Message::Maximize(pane))
+      };
+
+      // The last pane can't be closed, and closing a maximized pane would leave nothing to restore to.
+      let close = Button::new(Text::new("Close").size(14))
+        .on_press_maybe((pane_count > 1 && !is_maximized).then_some(Message::Close(pane)))
+        .style(theme::Button::Destructive);
+
+      let title_bar = pane_grid::TitleBar::new(Text::new(format!("Pane {}", content.id)))
+        .controls(Row::with_children(vec![maximize.into(), Space::with_width(5).into(), close.into()]))
+        .padding(10);
+
+      let body = Container::new(Column::with_children(vec![
+        Button::new("Split horizontally").on_press(Message::Split(Axis::Horizontal, pane)).into(),
+        Button::new("Split vertically").on_press(Message::Split(Axis::Vertical, pane)).into(),
+      ]).spacing(10))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y();
+
+      pane_grid::Content::new(body)
+        .title_bar(title_bar)
+        .style(theme::Container::Custom(Box::new(PaneStyle { is_focused })))
+    })
+      .width(Length::Fill)
+      .height(500)
+      .spacing(10)
+      .on_click(Message::Clicked)
+      .on_drag(Message::Dragged)
+      .on_resize(10, Message::Resized);
+
+    Scrollable::new(
+      Column::with_children(vec![
+        utils::docs_link("PaneGrid", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html"))),
+        Text::new("A grid of panes that can be split, resized and rearranged, like the editors and panels of an IDE.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Element::new(pane_grid).explain(Color::BLACK),
+        Row::with_children(vec![
+          Button::new("Save layout").on_press(Message::SaveLayout).into(),
+          Space::with_width(10).into(),
+          Button::new("Load layout").on_press(Message::LoadLayout).into(),
+          Space::with_width(10).into(),
+          Text::new(self.layout_status.as_str()).into(),
+        ]).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("State", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.State.html"))),
+        Text::new("Unlike most widgets, a PaneGrid's layout lives in a pane_grid::State kept in the Application. State::new creates it with one pane and gives back that pane's id. Each pane holds a value of your own type, here a PaneContent with a number. split, close, swap, resize, maximize and restore change the layout, usually in response to the messages below.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.new"))),
+        Text::new("Creates a PaneGrid from the State and a closure that turns each pane into a pane_grid::Content. The closure gets the pane's id, its value, and whether it is maximized.").into(),
+
+        utils::docs_link("Content", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.Content.html"))),
+        Text::new("The body of a pane, with an optional title bar and a style. This example uses a custom style to draw a border around the focused pane.").into(),
+
+        utils::docs_link("TitleBar", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.TitleBar.html"))),
+        Text::new("The title of a pane. A pane with a title bar can only be dragged by it, so the widgets in the body stay clickable. controls puts widgets on the right of the title bar, like the Maximize and Close buttons.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_click", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.on_click"))),
+        Text::new("Sets the message that is produced when a pane is clicked. Used here to move the focus.").into(),
+
+        utils::docs_link("on_drag", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.on_drag"))),
+        Text::new("Makes panes draggable by their title bar. The DragEvent says when a drag starts, is dropped on another pane, or is canceled. Nothing moves unless update() acts on it. This example swaps the two panes on Dropped.").into(),
+
+        utils::docs_link("on_resize", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.on_resize"))),
+        Text::new("Makes the splits draggable. The number is how close to a split, in pixels, the mouse has to be to grab it. The ResizeEvent has the split and its new ratio, to pass to State::resize.").into(),
+
+        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.spacing"))),
+        Text::new("Sets the space between panes.").into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.width"))),
+        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/struct.PaneGrid.html#method.height"))),
+        Text::new("Set the size of the PaneGrid. It fills the available space by default. Inside a Scrollable, like on this page, it needs a fixed height.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Configuration", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pane_grid/enum.Configuration.html"))),
+        Text::new("A layout described as a tree of splits and panes. State::with_configuration builds a State from one, which is how a saved layout is restored. The other way, State::layout gives the current tree of Nodes. None of these types can be serialized, so layout.rs copies them into a tree of its own before writing it as JSON.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
\ No newline at end of file
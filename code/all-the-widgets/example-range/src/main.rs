@@ -0,0 +1,283 @@
+mod utils;
+
+use std::fmt::{Display, Formatter};
+use iced::widget::{slider, Button, Column, PickList, ProgressBar, Row, Rule, Scrollable, Slider, Space, Text, VerticalSlider};
+use iced::{executor, theme, Application, Color, Command, Element, Settings, Subscription, Theme};
+
+pub fn main() -> iced::Result {
+  RangeExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  IntegerChanged(i32),
+  FloatChanged(f32),
+  SteppedChanged(f32),
+  Dragged(f32),
+  Released,
+  VerticalChanged(u8),
+  LinkedChanged(f32),
+  SelectedProgressBarStyle(ProgressBarStyle),
+  Tick,
+  ToggleAnimation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressBarStyle {
+  Primary,
+  Success,
+  Danger,
+}
+
+impl Display for ProgressBarStyle {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ProgressBarStyle::Primary => write!(f, "Primary"),
+      ProgressBarStyle::Success => write!(f, "Success"),
+      ProgressBarStyle::Danger => write!(f, "Danger"),
+    }
+  }
+}
+
+const ALL_PROGRESS_BAR_STYLES: [ProgressBarStyle; 3] = [
+  ProgressBarStyle::Primary,
+  ProgressBarStyle::Success,
+  ProgressBarStyle::Danger,
+];
+
+/// The Theme's slider with a bigger, round handle.
+struct BigHandleStyle {}
+
+impl slider::StyleSheet for BigHandleStyle {
+  type Style = Theme;
+
+  fn active(&self, style: &Self::Style) -> slider::Appearance {
+    BigHandleStyle::grow(slider::StyleSheet::active(style, &theme::Slider::Default))
+  }
+
+  fn hovered(&self, style: &Self::Style) -> slider::Appearance {
+    BigHandleStyle::grow(slider::StyleSheet::hovered(style, &theme::Slider::Default))
+  }
+
+  fn dragging(&self, style: &Self::Style) -> slider::Appearance {
+    BigHandleStyle::grow(slider::StyleSheet::dragging(style, &theme::Slider::Default))
+  }
+}
+
+impl BigHandleStyle {
+  fn grow(appearance: slider::Appearance) -> slider::Appearance {
+    slider::Appearance {
+      handle: slider::Handle {
+        shape: slider::HandleShape::Circle { radius: 12.0 },
+        ..appearance.handle
+      },
+      ..appearance
+    }
+  }
+}
+
+struct RangeExample {
+  integer: i32,
+  float: f32,
+  stepped: f32,
+  dragged: f32,
+  /// Where the on_release Slider was let go, if it has been used yet.
+  released_at: Option<f32>,
+  vertical: u8,
+  linked: f32,
+  progress_bar_style: ProgressBarStyle,
+  animated: f32,
+  is_animating: bool,
+}
+
+impl Application for RangeExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      RangeExample {
+        integer: 50,
+        float: 0.5,
+        stepped: 50.0,
+        dragged: 50.0,
+        released_at: None,
+        vertical: 50,
+        linked: 30.0,
+        progress_bar_style: ProgressBarStyle::Primary,
+        animated: 0.0,
+        is_animating: true,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Range Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::IntegerChanged(integer) => self.integer = integer,
+      Message::FloatChanged(float) => self.float = float,
+      Message::SteppedChanged(stepped) => self.stepped = stepped,
+      Message::Dragged(dragged) => self.dragged = dragged,
+      Message::Released => self.released_at = Some(self.dragged),
+      Message::VerticalChanged(vertical) => self.vertical = vertical,
+      Message::LinkedChanged(linked) => self.linked = linked,
+      Message::SelectedProgressBarStyle(progress_bar_style) => self.progress_bar_style = progress_bar_style,
+      Message::Tick => {
+        // Fill up in about 5 seconds at 60 ticks per second, then start over.
+        self.animated += 100.0 / 300.0;
+        if self.animated > 100.0 {
+          self.animated = 0.0;
+        }
+      }
+      Message::ToggleAnimation => self.is_animating = !self.is_animating,
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let released_at = match self.released_at {
+      Some(released_at) => format!("Released at {}", released_at),
+      None => String::from("Not released yet"),
+    };
+
+    Scrollable::new(
+      Column::with_children(vec![
+        Text::new("Range Widgets").size(48).into(),
+        Text::new("Widgets for a value inside a range. Sliders let the user pick it, a ProgressBar only shows it.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Slider", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html"))),
+        Text::new("A bar with a handle that can be dragged to pick a value.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.new"))),
+        Slider::new(0..=100, self.integer, Message::IntegerChanged).into(),
+        Text::new(format!("Value: {}", self.integer)).into(),
+        Slider::new(0.0..=1.0, self.float, Message::FloatChanged).step(0.01).into(),
+        Text::new(format!("Value: {:.2}", self.float)).into(),
+        Text::new("Creates a Slider with an inclusive range, the current value, and a function turning a new value into a Message. The range sets the type: 0..=100 gives integers and 0.0..=1.0 gives floats. Any number type that converts into an f64 (Into<f64>) works, so not i64 or u64.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("step", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.step"))),
+        Slider::new(0.0..=100.0, self.stepped, Message::SteppedChanged).step(10.0).into(),
+        Text::new(format!("Value: {}", self.stepped)).into(),
+        Text::new("Sets how much the value changes at a time. The default is 1, so a float Slider needs a smaller step to produce anything between whole numbers.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_release", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.on_release"))),
+        Slider::new(0.0..=100.0, self.dragged, Message::Dragged).on_release(Message::Released).into(),
+        Text::new(format!("Value: {}, {}", self.dragged, released_at)).into(),
+        Text::new("Sets the message that is produced when the handle is let go. Useful when every change is expensive, like saving or searching. The on_change messages keep coming while dragging.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.width"))),
+        Element::new(Slider::new(0..=100, self.integer, Message::IntegerChanged).width(200)).explain(Color::BLACK),
+        Text::new("Sets the width of the Slider. It fills the available width by default.").into(),
+
+        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.height"))),
+        Element::new(Slider::new(0..=100, self.integer, Message::IntegerChanged).height(40)).explain(Color::BLACK),
+        Text::new("Sets the height of the Slider. Only the space it takes up grows, the rail stays the same.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/slider/struct.Slider.html#method.style"))),
+        Slider::new(0..=100, self.integer, Message::IntegerChanged).style(theme::Slider::Custom(Box::new(BigHandleStyle {}))).into(),
+        Text::new("Sets the style of the Slider. The built-in Theme only has a Default style, so this one uses a custom StyleSheet that starts from the Theme's look and changes the handle.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("VerticalSlider", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/vertical_slider/struct.VerticalSlider.html"))),
+        Text::new("A Slider standing up. It has the same methods as Slider, with width and height swapped in meaning.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/vertical_slider/struct.VerticalSlider.html#method.new"))),
+        Row::with_children(vec![
+          VerticalSlider::new(0..=100, self.vertical, Message::VerticalChanged).height(200).into(),
+          Space::with_width(20).into(),
+          VerticalSlider::new(0..=100, self.vertical, Message::VerticalChanged).height(200).step(25).into(),
+          Space::with_width(20).into(),
+          Element::new(VerticalSlider::new(0..=100, self.vertical, Message::VerticalChanged).height(200).width(40)).explain(Color::BLACK),
+          Space::with_width(20).into(),
+          Text::new(format!("Value: {}", self.vertical)).into(),
+        ]).into(),
+        Text::new("Creates a VerticalSlider. The bottom is the start of the range. From left to right: a plain one with height, one with step(25), and one with width(40).").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("ProgressBar", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/progress_bar/struct.ProgressBar.html"))),
+        Text::new("A bar that fills up to show how far along something is. It can't be dragged.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/progress_bar/struct.ProgressBar.html#method.new"))),
+        ProgressBar::new(0.0..=100.0, self.linked).into(),
+        Slider::new(0.0..=100.0, self.linked, Message::LinkedChanged).into(),
+        Text::new("Creates a ProgressBar with an inclusive f32 range and the current value. Here a Slider and a ProgressBar share one value, so dragging the Slider fills the bar.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/progress_bar/struct.ProgressBar.html#method.width"))),
+        ProgressBar::new(0.0..=100.0, self.linked).width(200).into(),
+        Text::new("Sets the width of the ProgressBar. It fills the available width by default.").into(),
+
+        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/progress_bar/struct.ProgressBar.html#method.height"))),
+        ProgressBar::new(0.0..=100.0, self.linked).height(5).into(),
+        Text::new("Sets the height of the ProgressBar. The default is 30.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/progress_bar/struct.ProgressBar.html#method.style"))),
+        ProgressBar::new(0.0..=100.0, self.linked).style(match self.progress_bar_style {
+          ProgressBarStyle::Primary => theme::ProgressBar::Primary,
+          ProgressBarStyle::Success => theme::ProgressBar::Success,
+          ProgressBarStyle::Danger => theme::ProgressBar::Danger,
+        }).into(),
+        Row::with_children(vec![
+          Text::new("Style:").into(),
+          Space::with_width(10).into(),
+          PickList::new(ALL_PROGRESS_BAR_STYLES.to_vec(), Some(self.progress_bar_style), Message::SelectedProgressBarStyle).into(),
+        ]).into(),
+        Text::new("Sets the style variant of this ProgressBar.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Animation").size(32).into(),
+        ProgressBar::new(0.0..=100.0, self.animated).into(),
+        Button::new(if self.is_animating { "Pause" } else { "Resume" }).on_press(Message::ToggleAnimation).into(),
+        Text::new("A ProgressBar driven by iced::time::every, the same timer Subscription basic-state-2 uses for its clock. Each tick moves the value a little and the bar follows.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    if !self.is_animating {
+      return Subscription::none();
+    }
+
+    iced::time::every(std::time::Duration::from_millis(17)).map(|_| Message::Tick)
+  }
+}
\ No newline at end of file
@@ -0,0 +1,169 @@
+mod utils;
+
+use std::cell::Cell;
+use iced::widget::{lazy, responsive, Button, Column, Container, Row, Rule, Scrollable, Slider, Space, Text};
+use iced::{executor, theme, Application, Color, Command, Element, Length, Settings, Size, Theme};
+
+pub fn main() -> iced::Result {
+  ResponsiveLazyExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  WidthChanged(u16),
+  LimitChanged(u32),
+  Increment,
+}
+
+/// Below this width the cards are stacked in a Column instead of a Row.
+const BREAKPOINT: f32 = 600.0;
+
+struct ResponsiveLazyExample {
+  width: u16,
+  limit: u32,
+  unrelated: u32,
+  /// Cells, because view() only gets &self. Counting is the only thing this page mutates from view().
+  view_runs: Cell<usize>,
+  lazy_runs: Cell<usize>,
+}
+
+impl Application for ResponsiveLazyExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      ResponsiveLazyExample {
+        width: 800,
+        limit: 100_000,
+        unrelated: 0,
+        view_runs: Cell::new(0),
+        lazy_runs: Cell::new(0),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Responsive and Lazy Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::WidthChanged(width) => self.width = width,
+      Message::LimitChanged(limit) => self.limit = limit,
+      Message::Increment => self.unrelated += 1,
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    self.view_runs.set(self.view_runs.get() + 1);
+
+    let cards = responsive(|size: Size| {
+      let cards = vec![
+        card("First", size.width),
+        card("Second", size.width),
+        card("Third", size.width),
+      ];
+
+      if size.width < BREAKPOINT {
+        Column::with_children(cards).spacing(10).into()
+      } else {
+        Row::with_children(cards).spacing(10).into()
+      }
+    });
+
+    let lazy_runs = &self.lazy_runs;
+    let primes = lazy(self.limit, move |limit| {
+      lazy_runs.set(lazy_runs.get() + 1);
+
+      let primes = primes_below(*limit);
+      let largest: Vec<String> = primes.iter().rev().take(10).map(|prime| prime.to_string()).collect();
+
+      Column::with_children(vec![
+        Text::new(format!("There are {} primes below {}.", primes.len(), limit)).into(),
+        Text::new(format!("The largest are {}.", largest.join(", "))).into(),
+      ])
+    });
+
+    Scrollable::new(
+      Column::with_children(vec![
+        Text::new("Responsive and Lazy").size(48).into(),
+        Text::new("Two widgets that wrap a closure building other widgets. responsive decides the layout from the available size, lazy skips rebuilding a subtree that hasn't changed. Both need the \"lazy\" feature of iced.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("responsive", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/fn.responsive.html"))),
+        Element::new(Container::new(cards).width(self.width).height(250)).explain(Color::BLACK),
+        Row::with_children(vec![
+          Text::new(format!("Width: {}", self.width)).width(100).into(),
+          Space::with_width(10).into(),
+          Slider::new(200..=1000, self.width, Message::WidthChanged).width(400).into(),
+        ]).into(),
+        Text::new(format!("Creates a Responsive from a closure that gets the Size the widget is given and returns the content for it. Here the cards sit in a Row when there are at least {} pixels, and in a Column below that. Drag the slider to change the width of the box. The Row and Column pages show what each of them does on its own.", BREAKPOINT)).into(),
+        Text::new("A Responsive takes all the space it is given, so inside a Scrollable it needs a parent with a fixed height.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("lazy", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/fn.lazy.html"))),
+        Element::new(primes).explain(Color::BLACK),
+        Row::with_children(vec![
+          Text::new(format!("Limit: {}", self.limit)).width(150).into(),
+          Space::with_width(10).into(),
+          Slider::new(1_000..=1_000_000, self.limit, Message::LimitChanged).step(1_000).width(400).into(),
+        ]).into(),
+        Row::with_children(vec![
+          Button::new("Increment").on_press(Message::Increment).into(),
+          Space::with_width(10).into(),
+          Text::new(format!("Unrelated counter: {}", self.unrelated)).into(),
+        ]).into(),
+        Text::new(format!("view() has run {} times, the lazy closure {} times.", self.view_runs.get(), self.lazy_runs.get())).size(24).into(),
+        Text::new("Creates a Lazy from a dependency and a closure that builds the content from it. The dependency is hashed, and the closure only runs again when the hash changes. Finding the primes is slow, so it is only redone when the limit changes. The Increment button changes other state: view() runs again, but the primes are not recomputed.").into(),
+        Text::new("The Element the closure returns has to be 'static, so it can't borrow from the Application, it is built from the dependency instead. The counts are read when view() runs and the lazy closure runs right after, so its count can be one behind.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
+
+fn card<'a>(title: &'a str, available_width: f32) -> Element<'a, Message> {
+  Container::new(Column::with_children(vec![
+    Text::new(title).size(24).into(),
+    Text::new(format!("Laid out for {:.0} pixels", available_width)).into(),
+  ]).spacing(5))
+    .width(Length::Fill)
+    .padding(20)
+    .style(theme::Container::Box)
+    .into()
+}
+
+/// The Sieve of Eratosthenes. Slow enough for big limits that running it on every view() would be noticeable.
+fn primes_below(limit: u32) -> Vec<u32> {
+  let limit = limit as usize;
+  let mut is_prime = vec![true; limit];
+  let mut primes = Vec::new();
+
+  for number in 2..limit {
+    if is_prime[number] {
+      primes.push(number as u32);
+
+      let mut multiple = number * number;
+      while multiple < limit {
+        is_prime[multiple] = false;
+        multiple += number;
+      }
+    }
+  }
+
+  primes
+}
\ No newline at end of file
@@ -1,7 +1,8 @@
 mod utils;
 
 use iced::widget::{Column, Row, Rule, Scrollable, Text};
-use iced::{executor, Application, Command, Element, Settings, Theme, Color, Alignment};
+use iced::widget::scrollable::{self, Viewport};
+use iced::{executor, Application, Command, Element, Length, Settings, Theme, Color, Alignment};
 
 pub fn main() -> iced::Result {
   RowExample::run(Settings::default())
@@ -10,9 +11,27 @@ pub fn main() -> iced::Result {
 #[derive(Debug, Clone)]
 pub enum Message {
   Open(String),
+  JumpTo(usize),
+  PageScrolled(Viewport),
 }
 
-struct RowExample {}
+/// The attributes on this page, in order, for the table of contents. Each one is a utils::section.
+const SECTIONS: [&str; 8] = [
+  "new",
+  "with_children",
+  "push",
+  "width",
+  "height",
+  "padding",
+  "spacing",
+  "align_items",
+];
+
+struct RowExample {
+  page_id: scrollable::Id,
+  /// How far the page is scrolled, to highlight the current section.
+  page_offset: f32,
+}
 
 impl Application for RowExample {
   type Executor = executor::Default;
@@ -23,6 +42,8 @@ impl Application for RowExample {
   fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
     (
       RowExample {
+        page_id: scrollable::Id::unique(),
+        page_offset: 0.0,
       },
       Command::none(),
     )
@@ -37,104 +58,115 @@ impl Application for RowExample {
       Message::Open(url) => {
         opener::open(url).ok();
       }
+      Message::JumpTo(index) => return utils::jump_to(self.page_id.clone(), index),
+      Message::PageScrolled(viewport) => self.page_offset = viewport.absolute_offset().y,
     }
     Command::none()
   }
 
   fn view(&self) -> Element<Message> {
-    Scrollable::new(
+    let page = Scrollable::new(
+      Column::with_children(vec![
+        utils::section(vec![
+          utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.new"))),
+          Element::new(Row::new()).explain(Color::BLACK),
+          Text::new("Creates an empty Row. By default width and height will try to fit its children. An empty Row will be 0 by 0.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.with_children"))),
+          Element::new(Row::with_children(vec![
+            Text::new("Child 1").into(),
+            Text::new("Child 2").into(),
+            Text::new("Child 3").into(),
+          ])).explain(Color::BLACK),
+          Text::new("Creates a Row with the given elements.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("push", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.push"))),
+          Element::new(Row::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            ).explain(Color::BLACK),
+          Text::new("Adds an element to the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.width"))),
+          Element::new(Row::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .width(300)).explain(Color::BLACK),
+          Text::new("Sets the width of the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.height"))),
+          Element::new(Row::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .height(50)).explain(Color::BLACK),
+          Text::new("Sets the height of the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.padding"))),
+          Element::new(Row::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .padding([5, 10, 15, 20])).explain(Color::BLACK),
+          Text::new("Sets the Padding of the Row.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.spacing"))),
+          Element::new(Row::new()
+            .push(Text::new("Child 1"))
+            .push(Text::new("Child 2"))
+            .push(Text::new("Child 3"))
+            .spacing(20)).explain(Color::BLACK),
+          Text::new("Sets the vertical spacing between elements.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("align_items", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.align_items"))),
+          Element::new(Row::new()
+            .push(Text::new("Alignment::Start"))
+            .push(Text::new("Alignment::Start"))
+            .push(Text::new("Alignment::Start"))
+            .align_items(Alignment::Start).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Row::new()
+            .push(Text::new("Alignment::Center"))
+            .push(Text::new("Alignment::Center"))
+            .push(Text::new("Alignment::Center"))
+            .align_items(Alignment::Center).width(600).height(100)).explain(Color::BLACK),
+          Element::new(Row::new()
+            .push(Text::new("Alignment::End"))
+            .push(Text::new("Alignment::End"))
+            .push(Text::new("Alignment::End"))
+            .align_items(Alignment::End).width(600).height(100)).explain(Color::BLACK),
+          Text::new("Sets the horizontal alignment of the contents of the Row.").into(),
+        ]),
+      ]).padding([0, 100, 100, 100])
+    )
+      .id(self.page_id.clone())
+      .on_scroll(Message::PageScrolled)
+      .height(Length::Fill);
+
+    Column::with_children(vec![
       Column::with_children(vec![
         utils::docs_link("Row", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html"))),
         Text::new("A container that distributes its contents vertically.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.new"))),
-        Element::new(Row::new()).explain(Color::BLACK),
-        Text::new("Creates an empty Row. By default width and height will try to fit its children. An empty Row will be 0 by 0.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.with_children"))),
-        Element::new(Row::with_children(vec![
-          Text::new("Child 1").into(),
-          Text::new("Child 2").into(),
-          Text::new("Child 3").into(),
-        ])).explain(Color::BLACK),
-        Text::new("Creates a Row with the given elements.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("with_children", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.push"))),
-        Element::new(Row::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          ).explain(Color::BLACK),
-        Text::new("Adds an element to the Row.").into(),
-
+        utils::table_of_contents(&SECTIONS, Some(utils::current_section(self.page_offset, SECTIONS.len())), Message::JumpTo),
         Rule::horizontal(10).into(),
-
-        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.width"))),
-        Element::new(Row::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .width(300)).explain(Color::BLACK),
-        Text::new("Sets the width of the Row.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.height"))),
-        Element::new(Row::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .height(50)).explain(Color::BLACK),
-        Text::new("Sets the height of the Row.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.padding"))),
-        Element::new(Row::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .padding([5, 10, 15, 20])).explain(Color::BLACK),
-        Text::new("Sets the Padding of the Row.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.spacing"))),
-        Element::new(Row::new()
-          .push(Text::new("Child 1"))
-          .push(Text::new("Child 2"))
-          .push(Text::new("Child 3"))
-          .spacing(20)).explain(Color::BLACK),
-        Text::new("Sets the vertical spacing between elements.").into(),
-
-        Rule::horizontal(10).into(),
-
-        utils::docs_link("align_items", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/struct.Row.html#method.align_items"))),
-        Element::new(Row::new()
-          .push(Text::new("Alignment::Start"))
-          .push(Text::new("Alignment::Start"))
-          .push(Text::new("Alignment::Start"))
-          .align_items(Alignment::Start).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Row::new()
-          .push(Text::new("Alignment::Center"))
-          .push(Text::new("Alignment::Center"))
-          .push(Text::new("Alignment::Center"))
-          .align_items(Alignment::Center).width(600).height(100)).explain(Color::BLACK),
-        Element::new(Row::new()
-          .push(Text::new("Alignment::End"))
-          .push(Text::new("Alignment::End"))
-          .push(Text::new("Alignment::End"))
-          .align_items(Alignment::End).width(600).height(100)).explain(Color::BLACK),
-        Text::new("Sets the horizontal alignment of the contents of the Row.").into(),
-
-      ]).padding([100, 100, 100, 100])
-    ).into()
+      ]).padding([50, 100, 0, 100]).into(),
+      page.into(),
+    ]).into()
   }
 
   fn theme(&self) -> Theme {
@@ -0,0 +1,189 @@
+mod utils;
+
+use iced::widget::scrollable::{self, AbsoluteOffset, Direction, Properties, RelativeOffset, Viewport};
+use iced::widget::{Button, Column, Row, Rule, Scrollable, Space, Text};
+use iced::{executor, Application, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  ScrollableExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  JumpTo(usize),
+  PageScrolled(Viewport),
+  DemoScrolled(Viewport),
+  SnapTo(f32),
+  ScrollTo(f32),
+}
+
+const SECTIONS: [&str; 8] = [
+  "Vertical",
+  "Horizontal",
+  "Both",
+  "Properties",
+  "Alignment",
+  "On Scroll",
+  "Snap To",
+  "Scroll To",
+];
+
+struct ScrollableExample {
+  page_id: scrollable::Id,
+  /// How far the page itself is scrolled, to highlight the current section.
+  page_offset: f32,
+  absolute_offset: AbsoluteOffset,
+  relative_offset: RelativeOffset,
+  snap_id: scrollable::Id,
+  scroll_id: scrollable::Id,
+}
+
+impl Application for ScrollableExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      ScrollableExample {
+        page_id: scrollable::Id::unique(),
+        page_offset: 0.0,
+        absolute_offset: AbsoluteOffset { x: 0.0, y: 0.0 },
+        relative_offset: RelativeOffset { x: 0.0, y: 0.0 },
+        snap_id: scrollable::Id::unique(),
+        scroll_id: scrollable::Id::unique(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Scrollable Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::JumpTo(index) => {
+        return utils::jump_to(self.page_id.clone(), index);
+      }
+      Message::PageScrolled(viewport) => self.page_offset = viewport.absolute_offset().y,
+      Message::DemoScrolled(viewport) => {
+        self.absolute_offset = viewport.absolute_offset();
+        self.relative_offset = viewport.relative_offset();
+      }
+      Message::SnapTo(y) => return scrollable::snap_to(self.snap_id.clone(), RelativeOffset { x: 0.0, y }),
+      Message::ScrollTo(y) => return scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: 0.0, y }),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let current_section = utils::current_section(self.page_offset, SECTIONS.len());
+
+    let page = Scrollable::new(
+      Column::with_children(vec![
+        utils::section(vec![
+          utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Scrollable.html#method.new"))),
+          Scrollable::new(lines(50)).height(200).into(),
+          Text::new("Creates a Scrollable around some content. It scrolls vertically by default. Give it a height, or put it somewhere with limited space, otherwise it grows to fit its content and there is nothing to scroll.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("direction: Horizontal", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Scrollable.html#method.direction"))),
+          Scrollable::new(Row::with_children((1..=50).map(|index| Text::new(format!("Column {}", index)).into()).collect()).spacing(20))
+            .direction(Direction::Horizontal(Properties::default()))
+            .into(),
+          Text::new("Sets the direction the Scrollable scrolls in. A horizontal Scrollable lets its content be as wide as it wants.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("direction: Both", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Scrollable.html#method.direction"))),
+          Scrollable::new(Column::with_children((1..=30).map(|row| {
+            Row::with_children((1..=30).map(|column| Text::new(format!("{},{}", row, column)).width(60).into()).collect()).into()
+          }).collect()))
+            .direction(Direction::Both { vertical: Properties::default(), horizontal: Properties::default() })
+            .height(200)
+            .into(),
+          Text::new("Scrolls in both directions. Each direction gets its own Properties.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("Properties", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Properties.html"))),
+          Row::with_children(vec![
+            Scrollable::new(lines(50)).height(200).width(150).direction(Direction::Vertical(Properties::new().width(20))).into(),
+            Space::with_width(20).into(),
+            Scrollable::new(lines(50)).height(200).width(150).direction(Direction::Vertical(Properties::new().margin(10))).into(),
+            Space::with_width(20).into(),
+            Scrollable::new(lines(50)).height(200).width(150).direction(Direction::Vertical(Properties::new().width(20).scroller_width(5))).into(),
+          ]).into(),
+          Text::new("Properties change the scrollbar. From left to right: width sets the width of the rail, margin the space around it, and scroller_width the width of the part you drag.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("alignment", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Properties.html#method.alignment"))),
+          Scrollable::new(lines(50)).height(200).direction(Direction::Vertical(Properties::new().alignment(scrollable::Alignment::End))).into(),
+          Text::new("Sets where the content starts. With Alignment::End the Scrollable starts at the bottom and stays there as content is added, the way a chat or log would.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("on_scroll", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Scrollable.html#method.on_scroll"))),
+          Scrollable::new(lines(50)).height(200).on_scroll(Message::DemoScrolled).into(),
+          Text::new(format!("Absolute offset: {:.0}px, relative offset: {:.2}", self.absolute_offset.y, self.relative_offset.y)).into(),
+          Text::new("Sets the message that is produced when the Scrollable is scrolled. The Viewport tells how far it is scrolled, in pixels and as a fraction of the way. This page uses it to highlight the current section above.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("snap_to", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/fn.snap_to.html"))),
+          Scrollable::new(lines(50)).height(200).id(self.snap_id.clone()).into(),
+          Row::with_children(vec![
+            Button::new("Top").on_press(Message::SnapTo(0.0)).into(),
+            Space::with_width(10).into(),
+            Button::new("Middle").on_press(Message::SnapTo(0.5)).into(),
+            Space::with_width(10).into(),
+            Button::new("Bottom").on_press(Message::SnapTo(1.0)).into(),
+          ]).into(),
+          Text::new("A Command that scrolls the Scrollable with the given Id to a RelativeOffset, where 0.0 is the start and 1.0 is the end. Handy when you don't know how tall the content is.").into(),
+        ]),
+
+        utils::section(vec![
+          utils::docs_link("scroll_to", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/fn.scroll_to.html"))),
+          Scrollable::new(lines(50)).height(200).id(self.scroll_id.clone()).into(),
+          Row::with_children(vec![
+            Button::new("0px").on_press(Message::ScrollTo(0.0)).into(),
+            Space::with_width(10).into(),
+            Button::new("300px").on_press(Message::ScrollTo(300.0)).into(),
+            Space::with_width(10).into(),
+            Button::new("600px").on_press(Message::ScrollTo(600.0)).into(),
+          ]).into(),
+          Text::new("A Command that scrolls the Scrollable with the given Id to an AbsoluteOffset in pixels. The table of contents uses it: all sections are the same height, so section n starts at n times that height.").into(),
+        ]),
+      ]).padding([0, 100, 100, 100])
+    )
+      .id(self.page_id.clone())
+      .on_scroll(Message::PageScrolled)
+      .height(Length::Fill);
+
+    Column::with_children(vec![
+      Column::with_children(vec![
+        utils::docs_link("Scrollable", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/scrollable/struct.Scrollable.html"))),
+        Text::new("A widget that can show content bigger than itself by scrolling. Every gallery page is wrapped in one.").into(),
+        utils::table_of_contents(&SECTIONS, Some(current_section), Message::JumpTo),
+        Rule::horizontal(10).into(),
+      ]).padding([50, 100, 0, 100]).into(),
+      page.into(),
+    ]).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
+
+fn lines<'a>(count: usize) -> Element<'a, Message> {
+  Column::with_children((1..=count).map(|index| Text::new(format!("Line {}", index)).into()).collect()).width(Length::Fill).into()
+}
\ No newline at end of file
@@ -0,0 +1,384 @@
+mod utils;
+
+use std::fmt::{Display, Formatter};
+use iced::widget::{checkbox, combo_box, pick_list, text, Checkbox, Column, ComboBox, PickList, Radio, Row, Rule, Scrollable, Space, Text, Toggler};
+use iced::{alignment, executor, theme, Application, Color, Command, Element, Font, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  SelectionExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  Checked(bool),
+  SelectedCheckboxStyle(CheckboxStyle),
+  Radio(Fruit),
+  Toggled(bool),
+  Picked(Fruit),
+  ComboBoxSelected(Fruit),
+  ComboBoxInput(String),
+  ComboBoxHovered(Fruit),
+  ComboBoxClosed,
+}
+
+/// Radio needs values that are Copy and Eq. PickList and ComboBox need Display to show them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fruit {
+  Apple,
+  Banana,
+  Cherry,
+  Durian,
+}
+
+impl Display for Fruit {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Fruit::Apple => write!(f, "Apple"),
+      Fruit::Banana => write!(f, "Banana"),
+      Fruit::Cherry => write!(f, "Cherry"),
+      Fruit::Durian => write!(f, "Durian"),
+    }
+  }
+}
+
+const ALL_FRUITS: [Fruit; 4] = [
+  Fruit::Apple,
+  Fruit::Banana,
+  Fruit::Cherry,
+  Fruit::Durian,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxStyle {
+  Primary,
+  Secondary,
+  Success,
+  Danger,
+}
+
+impl Display for CheckboxStyle {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      CheckboxStyle::Primary => write!(f, "Primary"),
+      CheckboxStyle::Secondary => write!(f, "Secondary"),
+      CheckboxStyle::Success => write!(f, "Success"),
+      CheckboxStyle::Danger => write!(f, "Danger"),
+    }
+  }
+}
+
+const ALL_CHECKBOX_STYLES: [CheckboxStyle; 4] = [
+  CheckboxStyle::Primary,
+  CheckboxStyle::Secondary,
+  CheckboxStyle::Success,
+  CheckboxStyle::Danger,
+];
+
+struct SelectionExample {
+  is_checked: bool,
+  checkbox_style: CheckboxStyle,
+  radio: Option<Fruit>,
+  is_toggled: bool,
+  picked: Option<Fruit>,
+  /// One State per ComboBox on the page. The State also holds the typed text and the focus, so demos sharing one
+  /// would all change together.
+  combo_box_states: [combo_box::State<Fruit>; 8],
+  combo_box_selected: Option<Fruit>,
+  /// The last message the ComboBox produced, other than a selection.
+  combo_box_event: String,
+}
+
+impl Application for SelectionExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      SelectionExample {
+        is_checked: true,
+        checkbox_style: CheckboxStyle::Primary,
+        radio: None,
+        is_toggled: false,
+        picked: None,
+        combo_box_states: std::array::from_fn(|_| combo_box::State::new(ALL_FRUITS.to_vec())),
+        combo_box_selected: None,
+        combo_box_event: String::from("None yet"),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Selection Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::Checked(is_checked) => self.is_checked = is_checked,
+      Message::SelectedCheckboxStyle(checkbox_style) => self.checkbox_style = checkbox_style,
+      Message::Radio(fruit) => self.radio = Some(fruit),
+      Message::Toggled(is_toggled) => self.is_toggled = is_toggled,
+      Message::Picked(fruit) => self.picked = Some(fruit),
+      Message::ComboBoxSelected(fruit) => {
+        self.combo_box_selected = Some(fruit);
+        self.combo_box_event = format!("Selected {}", fruit);
+        for state in &mut self.combo_box_states {
+          state.unfocus();
+        }
+      }
+      Message::ComboBoxInput(input) => self.combo_box_event = format!("Typed {:?}", input),
+      Message::ComboBoxHovered(fruit) => self.combo_box_event = format!("Hovered {}", fruit),
+      Message::ComboBoxClosed => self.combo_box_event = String::from("Closed"),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let fruit_name = |fruit: Option<Fruit>| match fruit {
+      Some(fruit) => fruit.to_string(),
+      None => String::from("None"),
+    };
+
+    Scrollable::new(
+      Column::with_children(vec![
+        Text::new("Selection Widgets").size(48).into(),
+        Text::new("Widgets for picking a value. None of them store the value, they show what view() gives them and produce a Message when the user picks something else.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Checkbox", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html"))),
+        Text::new("A box that can be checked. Every Checkbox below is bound to the same bool.").into(),
+        Text::new(format!("Value: {}", self.is_checked)).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.new"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).into(),
+        Text::new("Creates a Checkbox with a label, whether it is checked, and a function turning the new state into a Message.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.size"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).size(30).into(),
+        Text::new("Sets the size of the box.").into(),
+
+        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.spacing"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).spacing(50).into(),
+        Text::new("Sets the space between the box and the label.").into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.width"))),
+        Element::new(Checkbox::new("A checkbox", self.is_checked, Message::Checked).width(300)).explain(Color::BLACK),
+        Text::new("Sets the width of the Checkbox, label included.").into(),
+
+        utils::docs_link("text_size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.text_size"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).text_size(30).into(),
+        Text::new("Sets the size of the label.").into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.font"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).font(Font::MONOSPACE).into(),
+        Text::new("Sets the Font of the label.").into(),
+
+        utils::docs_link("icon", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.icon"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).icon(checkbox::Icon {
+          font: Font::default(),
+          code_point: 'x',
+          size: None,
+          line_height: text::LineHeight::default(),
+          shaping: text::Shaping::Basic,
+        }).into(),
+        Text::new("Sets the character drawn inside the box when it is checked.").into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/checkbox/struct.Checkbox.html#method.style"))),
+        Checkbox::new("A checkbox", self.is_checked, Message::Checked).style(match self.checkbox_style {
+          CheckboxStyle::Primary => theme::Checkbox::Primary,
+          CheckboxStyle::Secondary => theme::Checkbox::Secondary,
+          CheckboxStyle::Success => theme::Checkbox::Success,
+          CheckboxStyle::Danger => theme::Checkbox::Danger,
+        }).into(),
+        Row::with_children(vec![
+          Text::new("Style:").into(),
+          Space::with_width(10).into(),
+          PickList::new(ALL_CHECKBOX_STYLES.to_vec(), Some(self.checkbox_style), Message::SelectedCheckboxStyle).into(),
+        ]).into(),
+        Text::new("Sets the style variant of this Checkbox.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Radio", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html"))),
+        Text::new("One option out of many. Each Radio is a single option. Giving a group of them the same selected value makes them act as one choice.").into(),
+        Text::new(format!("Value: {}", fruit_name(self.radio))).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.new"))),
+        Column::with_children(ALL_FRUITS.iter().map(|fruit| Radio::new(fruit.to_string(), *fruit, self.radio, Message::Radio).into()).collect()).spacing(10).into(),
+        Text::new("Creates a Radio with a label, the value it stands for, the currently selected value, and a function turning its value into a Message. It is selected when the two values are equal.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.size"))),
+        Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).size(30).into(),
+        Text::new("Sets the size of the circle.").into(),
+
+        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.spacing"))),
+        Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).spacing(50).into(),
+        Text::new("Sets the space between the circle and the label.").into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.width"))),
+        Element::new(Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).width(300)).explain(Color::BLACK),
+        Text::new("Sets the width of the Radio, label included.").into(),
+
+        utils::docs_link("text_size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.text_size"))),
+        Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).text_size(30).into(),
+        Text::new("Sets the size of the label.").into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.font"))),
+        Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).font(Font::MONOSPACE).into(),
+        Text::new("Sets the Font of the label.").into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/radio/struct.Radio.html#method.style"))),
+        Radio::new("Apple", Fruit::Apple, self.radio, Message::Radio).style(theme::Radio::Default).into(),
+        Text::new("Sets the style of the Radio. The built-in Theme only has a Default style. Anything else needs a custom StyleSheet.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Toggler", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html"))),
+        Text::new("A switch that is on or off. It works like a Checkbox, but reads as a setting that takes effect right away.").into(),
+        Text::new(format!("Value: {}", self.is_toggled)).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.new"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).into(),
+        Toggler::new(None::<String>, self.is_toggled, Message::Toggled).into(),
+        Text::new("Creates a Toggler with an optional label, whether it is on, and a function turning the new state into a Message. A Toggler fills the available width by default, with the label on the left and the switch on the right.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.width"))),
+        Element::new(Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).width(300)).explain(Color::BLACK),
+        Text::new("Sets the width of the Toggler, label included.").into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.size"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).size(40).width(300).into(),
+        Text::new("Sets the size of the switch.").into(),
+
+        utils::docs_link("spacing", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.spacing"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).spacing(50).width(300).into(),
+        Text::new("Sets the minimum space between the label and the switch.").into(),
+
+        utils::docs_link("text_size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.text_size"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).text_size(30).width(300).into(),
+        Text::new("Sets the size of the label.").into(),
+
+        utils::docs_link("text_alignment", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.text_alignment"))),
+        Element::new(Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).text_alignment(alignment::Horizontal::Right).width(300)).explain(Color::BLACK),
+        Text::new("Sets where the label sits in the space left of the switch.").into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.font"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).font(Font::MONOSPACE).width(300).into(),
+        Text::new("Sets the Font of the label.").into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/toggler/struct.Toggler.html#method.style"))),
+        Toggler::new(String::from("A toggler"), self.is_toggled, Message::Toggled).style(theme::Toggler::Default).width(300).into(),
+        Text::new("Sets the style of the Toggler. Like Radio, the built-in Theme only has a Default style.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("PickList", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html"))),
+        Text::new("A dropdown of options. The options need Display for their labels, and PartialEq so the PickList can find the selected one.").into(),
+        Text::new(format!("Value: {}", fruit_name(self.picked))).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.new"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).into(),
+        Text::new("Creates a PickList with its options, the selected option if there is one, and a function turning the picked option into a Message.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("placeholder", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.placeholder"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).placeholder("Pick a fruit").into(),
+        Text::new("Sets the text shown while nothing is selected.").into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.width"))),
+        Element::new(PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).width(300)).explain(Color::BLACK),
+        Text::new("Sets the width of the PickList. By default it is as wide as its longest option.").into(),
+
+        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.padding"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).padding([5, 10, 15, 20]).into(),
+        Text::new("Sets the Padding of the PickList.").into(),
+
+        utils::docs_link("text_size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.text_size"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).text_size(30).into(),
+        Text::new("Sets the text size of the PickList and its menu.").into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.font"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).font(Font::MONOSPACE).into(),
+        Text::new("Sets the Font of the PickList and its menu.").into(),
+
+        utils::docs_link("handle", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.handle"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).handle(pick_list::Handle::Arrow { size: Some(24.0.into()) }).into(),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).handle(pick_list::Handle::None).into(),
+        Text::new("Sets the handle on the right of the PickList. It can be a bigger arrow, an icon, or nothing at all.").into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/pick_list/struct.PickList.html#method.style"))),
+        PickList::new(ALL_FRUITS.to_vec(), self.picked, Message::Picked).style(theme::PickList::Default).into(),
+        Text::new("Sets the style of the PickList. The built-in Theme only has a Default style.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("ComboBox", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html"))),
+        Text::new("A PickList you can type in to filter the options. The options live in a combo_box::State that is kept in the Application, one for each ComboBox, because filtering them on every view() would be slow for long lists.").into(),
+        Text::new(format!("Value: {}", fruit_name(self.combo_box_selected))).into(),
+        Text::new(format!("Last event: {}", self.combo_box_event)).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.new"))),
+        ComboBox::new(&self.combo_box_states[0], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).into(),
+        Text::new("Creates a ComboBox with its State, a placeholder, the selected option, and a function turning the selected option into a Message.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_input", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.on_input"))),
+        ComboBox::new(&self.combo_box_states[1], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).on_input(Message::ComboBoxInput).into(),
+        Text::new("Sets the message that is produced when the search text changes.").into(),
+
+        utils::docs_link("on_option_hovered", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.on_option_hovered"))),
+        ComboBox::new(&self.combo_box_states[2], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).on_option_hovered(Message::ComboBoxHovered).into(),
+        Text::new("Sets the message that is produced when an option is hovered, with the mouse or the arrow keys. Handy for previews.").into(),
+
+        utils::docs_link("on_close", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.on_close"))),
+        ComboBox::new(&self.combo_box_states[3], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).on_close(Message::ComboBoxClosed).into(),
+        Text::new("Sets the message that is produced when the menu closes without a selection.").into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.width"))),
+        Element::new(ComboBox::new(&self.combo_box_states[4], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).width(300)).explain(Color::BLACK),
+        Text::new("Sets the width of the ComboBox.").into(),
+
+        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.padding"))),
+        ComboBox::new(&self.combo_box_states[5], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).padding([5, 10, 15, 20]).into(),
+        Text::new("Sets the Padding of the ComboBox.").into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.size"))),
+        ComboBox::new(&self.combo_box_states[6], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).size(30.0).into(),
+        Text::new("Sets the text size of the ComboBox.").into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/combo_box/struct.ComboBox.html#method.font"))),
+        ComboBox::new(&self.combo_box_states[7], "Type a fruit", self.combo_box_selected.as_ref(), Message::ComboBoxSelected).font(Font::MONOSPACE).into(),
+        Text::new("Sets the Font of the ComboBox.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
\ No newline at end of file
@@ -0,0 +1,258 @@
+mod utils;
+
+use iced::widget::{text_input, Button, Column, Row, Rule, Scrollable, Space, Text, TextInput};
+use iced::{executor, theme, Application, Color, Command, Element, Font, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  TextInputExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  InputChanged(String),
+  Submitted,
+  Pasted(String),
+  PasswordChanged(String),
+  Focus,
+  SelectAll,
+}
+
+/// Red border while the value is empty, the way a form would flag a required field.
+struct RequiredInputStyle {
+  is_empty: bool,
+}
+
+impl text_input::StyleSheet for RequiredInputStyle {
+  type Style = Theme;
+
+  fn active(&self, style: &Self::Style) -> text_input::Appearance {
+    let appearance = text_input::StyleSheet::active(style, &theme::TextInput::Default);
+    self.flag(appearance)
+  }
+
+  fn focused(&self, style: &Self::Style) -> text_input::Appearance {
+    let appearance = text_input::StyleSheet::focused(style, &theme::TextInput::Default);
+    self.flag(appearance)
+  }
+
+  fn placeholder_color(&self, style: &Self::Style) -> Color {
+    text_input::StyleSheet::placeholder_color(style, &theme::TextInput::Default)
+  }
+
+  fn value_color(&self, style: &Self::Style) -> Color {
+    text_input::StyleSheet::value_color(style, &theme::TextInput::Default)
+  }
+
+  fn disabled_color(&self, style: &Self::Style) -> Color {
+    text_input::StyleSheet::disabled_color(style, &theme::TextInput::Default)
+  }
+
+  fn selection_color(&self, style: &Self::Style) -> Color {
+    text_input::StyleSheet::selection_color(style, &theme::TextInput::Default)
+  }
+
+  fn disabled(&self, style: &Self::Style) -> text_input::Appearance {
+    text_input::StyleSheet::disabled(style, &theme::TextInput::Default)
+  }
+}
+
+impl RequiredInputStyle {
+  fn flag(&self, appearance: text_input::Appearance) -> text_input::Appearance {
+    if self.is_empty {
+      text_input::Appearance {
+        border_color: Color::from_rgb(0.8, 0.2, 0.2),
+        border_width: 2.0,
+        ..appearance
+      }
+    } else {
+      appearance
+    }
+  }
+}
+
+struct TextInputExample {
+  value: String,
+  password: String,
+  /// The Messages the inputs produced, newest last.
+  event_log: Vec<String>,
+  focus_id: text_input::Id,
+}
+
+impl TextInputExample {
+  fn log(&mut self, event: String) {
+    self.event_log.push(event);
+
+    if self.event_log.len() > 10 {
+      self.event_log.remove(0);
+    }
+  }
+}
+
+impl Application for TextInputExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      TextInputExample {
+        value: String::from("Some text"),
+        password: String::new(),
+        event_log: Vec::new(),
+        focus_id: text_input::Id::unique(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Text Input Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::InputChanged(value) => {
+        self.log(format!("InputChanged({:?})", value));
+        self.value = value;
+      }
+      Message::Submitted => self.log(format!("Submitted with {:?}", self.value)),
+      Message::Pasted(value) => {
+        self.log(format!("Pasted({:?})", value));
+        self.value = value;
+      }
+      Message::PasswordChanged(password) => {
+        self.log(format!("PasswordChanged({} characters)", password.chars().count()));
+        self.password = password;
+      }
+      Message::Focus => return text_input::focus(self.focus_id.clone()),
+      Message::SelectAll => {
+        // Selecting only makes sense in a focused input, so do both.
+        return Command::batch(vec![text_input::focus(self.focus_id.clone()), text_input::select_all(self.focus_id.clone())]);
+      }
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let event_log = if self.event_log.is_empty() {
+      vec![Text::new("Type in any of the inputs above.").into()]
+    } else {
+      self.event_log.iter().map(|event| Text::new(event.as_str()).font(Font::MONOSPACE).into()).collect()
+    };
+
+    Scrollable::new(
+      Column::with_children(vec![
+        utils::docs_link("TextInput", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html"))),
+        Text::new("A field that can be filled with text. Every input on this page, except the password, is bound to the same String, so typing in one updates all of them.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.new"))),
+        TextInput::new("The placeholder", "").into(),
+        Text::new("Creates a TextInput with a placeholder and its current value. The placeholder is shown while the value is empty. A TextInput without on_input is disabled, like this one.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_input", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.on_input"))),
+        TextInput::new("Type something", &self.value).on_input(Message::InputChanged).into(),
+        Text::new("Sets the message that is produced when some text is typed. The TextInput does not store the value, it always shows the value it was created with. update() has to save the new value or typing will do nothing.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_submit", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.on_submit"))),
+        TextInput::new("Press Enter", &self.value).on_input(Message::InputChanged).on_submit(Message::Submitted).into(),
+        Text::new("Sets the message that is produced when Enter is pressed while the TextInput is focused.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("on_paste", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.on_paste"))),
+        TextInput::new("Paste something", &self.value).on_input(Message::InputChanged).on_paste(Message::Pasted).into(),
+        Text::new("Sets the message that is produced when text is pasted. It gets the whole new value, not just the pasted text. Without on_paste, pasting produces the on_input message.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("password", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.password"))),
+        TextInput::new("Password", &self.password).on_input(Message::PasswordChanged).password().into(),
+        Text::new("Hides the value behind dots. The value in your state is still plain text, and copying out of a password field is disabled.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("icon", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.icon"))),
+        TextInput::new("Left icon", &self.value).on_input(Message::InputChanged).icon(text_input::Icon {
+          font: Font::default(),
+          code_point: '@',
+          size: None,
+          spacing: 10.0,
+          side: text_input::Side::Left,
+        }).into(),
+        TextInput::new("Right icon", &self.value).on_input(Message::InputChanged).icon(text_input::Icon {
+          font: Font::default(),
+          code_point: '#',
+          size: Some(24.0.into()),
+          spacing: 10.0,
+          side: text_input::Side::Right,
+        }).into(),
+        Text::new("Adds a single character from a font as an icon. Icon fonts can be loaded and used the same way.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.padding"))),
+        TextInput::new("Padding", &self.value).on_input(Message::InputChanged).padding([5, 10, 15, 20]).into(),
+        Text::new("Sets the Padding between the border and the text.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.size"))),
+        TextInput::new("Size", &self.value).on_input(Message::InputChanged).size(32).into(),
+        Text::new("Sets the text size of the TextInput.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.width"))),
+        Element::new(TextInput::new("Width", &self.value).on_input(Message::InputChanged).width(200)).explain(Color::BLACK),
+        Text::new("Sets the width of the TextInput. It fills the available width by default.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.font"))),
+        TextInput::new("Font", &self.value).on_input(Message::InputChanged).font(Font::MONOSPACE).into(),
+        Text::new("Sets the Font of the TextInput.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.style"))),
+        TextInput::new("Clear me", &self.value).on_input(Message::InputChanged).style(theme::TextInput::Custom(Box::new(RequiredInputStyle { is_empty: self.value.is_empty() }))).into(),
+        Text::new("Sets the style of the TextInput. This custom StyleSheet reuses the Theme's appearance and adds a red border while the value is empty.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("id", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text_input/struct.TextInput.html#method.id"))),
+        TextInput::new("Focus me with the buttons", &self.value).on_input(Message::InputChanged).id(self.focus_id.clone()).into(),
+        Row::with_children(vec![
+          Button::new("Focus").on_press(Message::Focus).into(),
+          Space::with_width(10).into(),
+          Button::new("Select all").on_press(Message::SelectAll).into(),
+        ]).into(),
+        Text::new("Sets the Id of the TextInput. Commands like text_input::focus and text_input::select_all use it to find the input.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Event log").size(32).into(),
+        Column::with_children(event_log).into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
\ No newline at end of file
@@ -0,0 +1,180 @@
+mod utils;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::font::{Family, Weight};
+use iced::widget::text::{LineHeight, Shaping};
+use iced::widget::{Column, Radio, Row, Rule, Scrollable, Slider, Space, Text};
+use iced::{executor, theme, Application, Color, Command, Element, Font, Length, Pixels, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  TextExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  SizeChanged(u16),
+  HorizontalAlignment(Horizontal),
+  VerticalAlignment(Vertical),
+}
+
+const SHAPING_SAMPLES: [&str; 4] = [
+  "Hello, world!",
+  "مرحبا بالعالم",
+  "こんにちは世界",
+  "👋 🌍 🎉",
+];
+
+struct TextExample {
+  size: u16,
+  horizontal_alignment: Horizontal,
+  vertical_alignment: Vertical,
+}
+
+impl Application for TextExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      TextExample {
+        size: 30,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Text Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::SizeChanged(size) => self.size = size,
+      Message::HorizontalAlignment(horizontal_alignment) => self.horizontal_alignment = horizontal_alignment,
+      Message::VerticalAlignment(vertical_alignment) => self.vertical_alignment = vertical_alignment,
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let horizontal_alignment = Row::with_children(vec![
+      Text::new("Horizontal:").width(100).into(),
+      Radio::new("Left", Horizontal::Left, Some(self.horizontal_alignment), Message::HorizontalAlignment).into(),
+      Space::with_width(20).into(),
+      Radio::new("Center", Horizontal::Center, Some(self.horizontal_alignment), Message::HorizontalAlignment).into(),
+      Space::with_width(20).into(),
+      Radio::new("Right", Horizontal::Right, Some(self.horizontal_alignment), Message::HorizontalAlignment).into(),
+    ]);
+
+    let vertical_alignment = Row::with_children(vec![
+      Text::new("Vertical:").width(100).into(),
+      Radio::new("Top", Vertical::Top, Some(self.vertical_alignment), Message::VerticalAlignment).into(),
+      Space::with_width(20).into(),
+      Radio::new("Center", Vertical::Center, Some(self.vertical_alignment), Message::VerticalAlignment).into(),
+      Space::with_width(20).into(),
+      Radio::new("Bottom", Vertical::Bottom, Some(self.vertical_alignment), Message::VerticalAlignment).into(),
+    ]);
+
+    let shaping = Row::with_children(vec![
+      shaping_samples("Shaping::Basic", Shaping::Basic),
+      shaping_samples("Shaping::Advanced", Shaping::Advanced),
+    ]);
+
+    Scrollable::new(
+      Column::with_children(vec![
+        utils::docs_link("Text", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html"))),
+        Text::new("A paragraph of text. It is on every page of this gallery, usually with nothing but new().").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.new"))),
+        Element::new(Text::new("Some text")).explain(Color::BLACK),
+        Text::new("Creates a Text from anything that turns into a Cow<str>, so both &str and String work. Long text wraps at the edge of the available space.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.size"))),
+        Text::new("Some text").size(self.size).into(),
+        Slider::new(10..=80, self.size, Message::SizeChanged).width(300).into(),
+        Text::new(format!("Sets the size of the Text in pixels. The default is 16. This one is {}.", self.size)).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("line_height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.line_height"))),
+        Row::with_children(vec![
+          Element::new(Text::new("LineHeight::Relative(1.0)\nSecond line\nThird line").line_height(LineHeight::Relative(1.0))).explain(Color::BLACK),
+          Space::with_width(20).into(),
+          Element::new(Text::new("LineHeight::Relative(2.0)\nSecond line\nThird line").line_height(LineHeight::Relative(2.0))).explain(Color::BLACK),
+          Space::with_width(20).into(),
+          Element::new(Text::new("LineHeight::Absolute(40)\nSecond line\nThird line").line_height(LineHeight::Absolute(Pixels(40.0)))).explain(Color::BLACK),
+        ]).into(),
+        Text::new("Sets the height of each line, either relative to the text size or in pixels. The default is 1.2 times the size.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("font", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.font"))),
+        Text::new("Font::DEFAULT").font(Font::DEFAULT).into(),
+        Text::new("Font::MONOSPACE").font(Font::MONOSPACE).into(),
+        Text::new("Family::Serif").font(Font { family: Family::Serif, ..Font::DEFAULT }).into(),
+        Text::new("Weight::Bold").font(Font { weight: Weight::Bold, ..Font::DEFAULT }).into(),
+        Text::new("Sets the Font. A Font describes what you want, like a family and a weight, and the system picks the closest installed font. Fonts bundled with the app are loaded with iced::font::load.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.width"))),
+        Element::new(Text::new("This text is only 150 pixels wide, so it wraps.").width(150)).explain(Color::BLACK),
+        Text::new("Sets the width of the Text. Text wraps to fit it.").into(),
+
+        utils::docs_link("height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.height"))),
+        Element::new(Text::new("Some text").height(100)).explain(Color::BLACK),
+        Text::new("Sets the height of the Text. The extra space is left empty, or used by vertical_alignment.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("horizontal_alignment", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.horizontal_alignment"))),
+        utils::docs_link("vertical_alignment", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.vertical_alignment"))),
+        Element::new(Text::new("Aligned text").size(self.size).width(600).height(200).horizontal_alignment(self.horizontal_alignment).vertical_alignment(self.vertical_alignment)).explain(Color::BLACK),
+        horizontal_alignment.into(),
+        vertical_alignment.into(),
+        Text::new("Sets where the text sits inside the space given by width and height. Alignment only shows when the Text is bigger than its content, like this 600 by 200 box.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("shaping", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.shaping"))),
+        shaping.into(),
+        Text::new("Sets how characters are turned into glyphs. Basic is fast and fine for Latin text. Advanced is needed for scripts that join or reorder characters, like Arabic, and for emoji and font fallback. Advanced is slower, so it is not the default.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/text/struct.Text.html#method.style"))),
+        Text::new("Color::from_rgb(0.8, 0.2, 0.2)").style(Color::from_rgb(0.8, 0.2, 0.2)).into(),
+        Text::new("theme::Text::Color").style(theme::Text::Color(Color::from_rgb(0.2, 0.6, 0.2))).into(),
+        Text::new("theme::Text::Default").style(theme::Text::Default).into(),
+        Text::new("Sets the style of the Text. With the built-in Theme a style is just a color, and a Color can be passed directly.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
+
+fn shaping_samples<'a>(title: &'a str, shaping: Shaping) -> Element<'a, Message> {
+  let mut samples = vec![Text::new(title).size(24).into()];
+  samples.extend(SHAPING_SAMPLES.iter().map(|sample| Text::new(*sample).shaping(shaping).into()));
+
+  Column::with_children(samples).spacing(10).width(Length::Fill).into()
+}
\ No newline at end of file
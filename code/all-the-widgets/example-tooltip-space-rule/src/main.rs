@@ -0,0 +1,209 @@
+mod utils;
+
+use iced::widget::{rule, tooltip, Button, Column, Container, Row, Rule, Scrollable, Space, Text, Tooltip};
+use iced::{executor, theme, Application, Color, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  TooltipSpaceRuleExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Open(String),
+  DoNothing,
+}
+
+/// A Rule in the primary color, with its width, corners and length configurable.
+struct RuleStyle {
+  width: u16,
+  radius: f32,
+  fill_mode: rule::FillMode,
+}
+
+impl rule::StyleSheet for RuleStyle {
+  type Style = Theme;
+
+  fn appearance(&self, style: &Self::Style) -> rule::Appearance {
+    rule::Appearance {
+      color: style.palette().primary,
+      width: self.width,
+      radius: self.radius.into(),
+      fill_mode: self.fill_mode,
+    }
+  }
+}
+
+fn styled_rule<'a>(width: u16, radius: f32, fill_mode: rule::FillMode) -> Element<'a, Message> {
+  Rule::horizontal(20).style(theme::Rule::Custom(Box::new(RuleStyle { width, radius, fill_mode }))).into()
+}
+
+struct TooltipSpaceRuleExample {}
+
+impl Application for TooltipSpaceRuleExample {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      TooltipSpaceRuleExample {},
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Tooltip, Space and Rule Example")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Open(url) => {
+        opener::open(url).ok();
+      }
+      Message::DoNothing => {}
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    Scrollable::new(
+      Column::with_children(vec![
+        utils::docs_link("Tooltip", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html"))),
+        Text::new("Shows a short text while the mouse is over some content.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.new"))),
+        Row::with_children(vec![
+          Tooltip::new(Button::new("Top").on_press(Message::DoNothing), "A tooltip on top", tooltip::Position::Top).into(),
+          Space::with_width(20).into(),
+          Tooltip::new(Button::new("Bottom").on_press(Message::DoNothing), "A tooltip below", tooltip::Position::Bottom).into(),
+          Space::with_width(20).into(),
+          Tooltip::new(Button::new("Left").on_press(Message::DoNothing), "A tooltip on the left", tooltip::Position::Left).into(),
+          Space::with_width(20).into(),
+          Tooltip::new(Button::new("Right").on_press(Message::DoNothing), "A tooltip on the right", tooltip::Position::Right).into(),
+          Space::with_width(20).into(),
+          Tooltip::new(Button::new("FollowCursor").on_press(Message::DoNothing), "A tooltip that follows the cursor", tooltip::Position::FollowCursor).into(),
+        ]).into(),
+        Text::new("Creates a Tooltip with the content it belongs to, its text, and where to show it. Hover over the buttons. By default the tooltip has no background, so it needs a style to be readable on top of other widgets.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Attributes").size(32).into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("gap", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.gap"))),
+        Tooltip::new(Button::new("Hover me").on_press(Message::DoNothing), "20 pixels away", tooltip::Position::Right).gap(20.0).style(theme::Container::Box).into(),
+        Text::new("Sets the space between the content and the tooltip.").into(),
+
+        utils::docs_link("padding", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.padding"))),
+        Tooltip::new(Button::new("Hover me").on_press(Message::DoNothing), "Lots of padding", tooltip::Position::Right).padding(20.0).style(theme::Container::Box).into(),
+        Text::new("Sets the space between the edge of the tooltip and its text.").into(),
+
+        utils::docs_link("size", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.size"))),
+        Tooltip::new(Button::new("Hover me").on_press(Message::DoNothing), "Big text", tooltip::Position::Right).size(30).style(theme::Container::Box).into(),
+        Text::new("Sets the text size of the tooltip.").into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.style"))),
+        Tooltip::new(Button::new("Hover me").on_press(Message::DoNothing), "theme::Container::Box", tooltip::Position::Right).style(theme::Container::Box).into(),
+        Text::new("Sets the style of the tooltip. A tooltip is drawn in a Container, so it takes Container styles. Box gives it a background and a border.").into(),
+
+        utils::docs_link("snap_within_viewport", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/tooltip/struct.Tooltip.html#method.snap_within_viewport"))),
+        Row::with_children(vec![
+          Space::with_width(Length::Fill).into(),
+          Tooltip::new(Button::new("Snapped").on_press(Message::DoNothing), "This tooltip is too long to fit on the right", tooltip::Position::Right).style(theme::Container::Box).into(),
+          Space::with_width(20).into(),
+          Tooltip::new(Button::new("Not snapped").on_press(Message::DoNothing), "This tooltip is too long to fit on the right", tooltip::Position::Right).snap_within_viewport(false).style(theme::Container::Box).into(),
+        ]).into(),
+        Text::new("Sets whether a tooltip that doesn't fit is moved back into the window. It is on by default. Hover the buttons at the right edge to compare.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Space", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/space/struct.Space.html"))),
+        Text::new("An empty widget that only takes up room. It is how the other gallery pages put a gap between two widgets.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("with_width", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/space/struct.Space.html#method.with_width"))),
+        Row::with_children(vec![
+          Text::new("Left").into(),
+          Element::new(Space::with_width(100)).explain(Color::BLACK),
+          Text::new("Right").into(),
+        ]).into(),
+        Text::new("Creates a Space with a width. Its height is Shrink, so it adds nothing to the height of a Row.").into(),
+
+        utils::docs_link("with_height", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/space/struct.Space.html#method.with_height"))),
+        Column::with_children(vec![
+          Text::new("Top").into(),
+          Element::new(Space::with_height(50)).explain(Color::BLACK),
+          Text::new("Bottom").into(),
+        ]).into(),
+        Text::new("Creates a Space with a height, for gaps in a Column.").into(),
+
+        utils::docs_link("new", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/space/struct.Space.html#method.new"))),
+        Element::new(Space::new(200, 50)).explain(Color::BLACK),
+        Text::new("Creates a Space with both a width and a height.").into(),
+
+        Rule::horizontal(10).into(),
+
+        Text::new("Length::Fill").size(24).into(),
+        Element::new(Row::with_children(vec![
+          Text::new("Title").size(24).into(),
+          Space::with_width(Length::Fill).into(),
+          Button::new("Save").on_press(Message::DoNothing).into(),
+          Space::with_width(10).into(),
+          Button::new("Close").on_press(Message::DoNothing).into(),
+        ])).explain(Color::BLACK),
+        Element::new(Row::with_children(vec![
+          Space::with_width(Length::Fill).into(),
+          Text::new("Centered without a Container").into(),
+          Space::with_width(Length::Fill).into(),
+        ])).explain(Color::BLACK),
+        Text::new("A Space that fills takes up whatever is left, pushing the widgets around it apart. One between a title and some buttons moves the buttons to the right edge. Two of them share the leftover space evenly, which centers what is between them.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("Rule", 36.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/rule/struct.Rule.html"))),
+        Text::new("A line that separates content. Every section of these pages ends with one.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("horizontal", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/rule/struct.Rule.html#method.horizontal"))),
+        Element::new(Rule::horizontal(10)).explain(Color::BLACK),
+        Element::new(Rule::horizontal(50)).explain(Color::BLACK),
+        Text::new("Creates a horizontal Rule. The number is not the thickness of the line, it is the height of the space the Rule takes up. The line is drawn in the middle of it. That is why Rule::horizontal(10) works as a separator.").into(),
+
+        utils::docs_link("vertical", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/rule/struct.Rule.html#method.vertical"))),
+        Container::new(Row::with_children(vec![
+          Text::new("Left").into(),
+          Element::new(Rule::vertical(10)).explain(Color::BLACK),
+          Text::new("Middle").into(),
+          Element::new(Rule::vertical(50)).explain(Color::BLACK),
+          Text::new("Right").into(),
+        ])).height(50).into(),
+        Text::new("Creates a vertical Rule, for separating the children of a Row. It is as tall as it is allowed to be, so the Row needs a height.").into(),
+
+        Rule::horizontal(10).into(),
+
+        utils::docs_link("style", 24.0, Message::Open(String::from("https://docs.rs/iced/latest/iced/widget/rule/struct.Rule.html#method.style"))),
+        Text::new("width: 1").into(),
+        styled_rule(1, 0.0, rule::FillMode::Full),
+        Text::new("width: 8, radius: 4").into(),
+        styled_rule(8, 4.0, rule::FillMode::Full),
+        Text::new("FillMode::Percent(50.0)").into(),
+        styled_rule(4, 0.0, rule::FillMode::Percent(50.0)),
+        Text::new("FillMode::Padded(100)").into(),
+        styled_rule(4, 0.0, rule::FillMode::Padded(100)),
+        Text::new("FillMode::AsymmetricPadding(0, 300)").into(),
+        styled_rule(4, 0.0, rule::FillMode::AsymmetricPadding(0, 300)),
+        Text::new("Sets the style of the Rule with a rule::StyleSheet. Its Appearance has the color, the width, which is the actual thickness of the line, the radius of its corners, and a FillMode for how much of the length it covers.").into(),
+      ]).padding([100, 100, 100, 100])
+    ).into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+mod worker;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::futures::channel::mpsc;
+use iced::widget::{Button, Column, Container, ProgressBar, Row, Text};
+use iced::{executor, Alignment, Application, Command, Element, Length, Settings, Subscription, Theme};
+
+pub fn main() -> iced::Result {
+  BackgroundWorker::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Worker(worker::Event),
+  Send(worker::Input),
+  StartWorker,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+  Idle,
+  Indexing,
+  Paused,
+}
+
+struct BackgroundWorker {
+  is_worker_running: bool,
+  /// Only available once the worker reported that it is ready.
+  sender: Option<mpsc::Sender<worker::Input>>,
+  status: Status,
+  indexed: usize,
+  total: usize,
+  current: String,
+  log: Vec<String>,
+}
+
+impl Application for BackgroundWorker {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      BackgroundWorker {
+        is_worker_running: true,
+        sender: None,
+        status: Status::Idle,
+        indexed: 0,
+        total: 0,
+        current: String::new(),
+        log: Vec::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Background Worker")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Worker(event) => match event {
+        worker::Event::Ready(sender) => {
+          self.sender = Some(sender);
+          self.log.push(String::from("Worker ready"));
+        }
+        worker::Event::Progress {
+          indexed,
+          total,
+          current,
+        } => {
+          self.status = Status::Indexing;
+          self.indexed = indexed;
+          self.total = total;
+          self.current = current;
+        }
+        worker::Event::Paused => self.status = Status::Paused,
+        worker::Event::Finished { indexed } => {
+          self.status = Status::Idle;
+          self.log.push(format!("Indexed {} files", indexed));
+        }
+        worker::Event::Cancelled => {
+          self.status = Status::Idle;
+          self
+            .log
+            .push(format!("Cancelled after {} files", self.indexed));
+        }
+        worker::Event::Stopped => {
+          // Dropping the Subscription stops the worker for good.
+          self.is_worker_running = false;
+          self.sender = None;
+          self.status = Status::Idle;
+          self.log.push(String::from("Worker stopped"));
+        }
+      },
+      Message::Send(input) => {
+        if let Some(sender) = &mut self.sender {
+          // try_send never blocks update. It only fails if the channel is full or the worker is gone.
+          if sender.try_send(input).is_err() {
+            self.log.push(format!("Worker did not accept {:?}", input));
+          }
+
+          if let worker::Input::Resume = input {
+            self.status = Status::Indexing;
+          }
+        }
+      }
+      Message::StartWorker => self.is_worker_running = true,
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let is_ready = self.sender.is_some();
+
+    let controls = Row::with_children(vec![
+      send_button(
+        "Start",
+        worker::Input::Start,
+        is_ready && self.status == Status::Idle,
+      ),
+      send_button(
+        "Pause",
+        worker::Input::Pause,
+        is_ready && self.status == Status::Indexing,
+      ),
+      send_button(
+        "Resume",
+        worker::Input::Resume,
+        is_ready && self.status == Status::Paused,
+      ),
+      send_button(
+        "Cancel",
+        worker::Input::Cancel,
+        is_ready && self.status != Status::Idle,
+      ),
+      send_button("Shutdown", worker::Input::Shutdown, is_ready),
+      Button::new("Restart worker")
+        .on_press_maybe((!self.is_worker_running).then_some(Message::StartWorker))
+        .into(),
+    ])
+    .spacing(10);
+
+    let progress = ProgressBar::new(0.0..=self.total.max(1) as f32, self.indexed as f32);
+
+    let mut children = vec![
+      controls.into(),
+      progress.into(),
+      Text::new(format!(
+        "{} / {} {}",
+        self.indexed, self.total, self.current
+      ))
+      .into(),
+    ];
+    children.extend(
+      self
+        .log
+        .iter()
+        .map(|entry| Text::new(entry.as_str()).into()),
+    );
+
+    let column = Column::with_children(children)
+      .spacing(20)
+      .max_width(600)
+      .align_items(Alignment::Center);
+
+    Container::new(column)
+      .align_x(Horizontal::Center)
+      .align_y(Vertical::Center)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    if !self.is_worker_running {
+      return Subscription::none();
+    }
+
+    worker::connect().map(Message::Worker)
+  }
+}
+
+/// A button that sends an Input to the worker.
+fn send_button(label: &str, input: worker::Input, is_enabled: bool) -> Element<Message> {
+  Button::new(label)
+    .on_press_maybe(is_enabled.then_some(Message::Send(input)))
+    .into()
+}
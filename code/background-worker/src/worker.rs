@@ -0,0 +1,147 @@
+use iced::futures::channel::mpsc;
+use iced::futures::SinkExt;
+use iced::futures::StreamExt;
+use iced::{subscription, Subscription};
+
+/// Messages the worker sends to the Application.
+#[derive(Debug, Clone)]
+pub enum Event {
+  /// The worker is running. Use the sender to give it commands.
+  Ready(mpsc::Sender<Input>),
+  Progress {
+    indexed: usize,
+    total: usize,
+    current: String,
+  },
+  Paused,
+  Finished {
+    indexed: usize,
+  },
+  Cancelled,
+  Stopped,
+}
+
+/// Commands the Application sends to the worker.
+#[derive(Debug, Clone, Copy)]
+pub enum Input {
+  Start,
+  Pause,
+  Resume,
+  Cancel,
+  Shutdown,
+}
+
+enum State {
+  Idle,
+  Indexing {
+    files: Vec<String>,
+    next: usize,
+    is_paused: bool,
+  },
+  Stopped,
+}
+
+/// A simulated file indexer running in the background.
+///
+/// The Subscription is identified by the id passed to `channel`. As long as the Application keeps returning it, Iced keeps
+/// the same worker running instead of starting a new one.
+pub fn connect() -> Subscription<Event> {
+  struct Worker;
+
+  subscription::channel(
+    std::any::TypeId::of::<Worker>(),
+    100,
+    |mut output| async move {
+      // The Application can't talk to the worker until we hand it a sender.
+      let (sender, mut receiver) = mpsc::channel(100);
+      let _ = output.send(Event::Ready(sender)).await;
+
+      let mut state = State::Idle;
+
+      // The future given to channel never returns. The worker is stopped by dropping the Subscription.
+      loop {
+        match &mut state {
+          State::Idle => match receiver.select_next_some().await {
+            Input::Start => {
+              state = State::Indexing {
+                files: fake_files(),
+                next: 0,
+                is_paused: false,
+              }
+            }
+            Input::Shutdown => {
+              let _ = output.send(Event::Stopped).await;
+              state = State::Stopped;
+            }
+            Input::Pause | Input::Resume | Input::Cancel => {}
+          },
+          State::Indexing {
+            files,
+            next,
+            is_paused,
+          } => {
+            // Don't block on commands while working, only look at the ones that already arrived.
+            let input = if *is_paused {
+              Some(receiver.select_next_some().await)
+            } else {
+              receiver.try_next().ok().flatten()
+            };
+
+            match input {
+              Some(Input::Pause) => {
+                *is_paused = true;
+                let _ = output.send(Event::Paused).await;
+                continue;
+              }
+              Some(Input::Resume) => *is_paused = false,
+              Some(Input::Cancel) => {
+                let _ = output.send(Event::Cancelled).await;
+                state = State::Idle;
+                continue;
+              }
+              Some(Input::Shutdown) => {
+                let _ = output.send(Event::Stopped).await;
+                state = State::Stopped;
+                continue;
+              }
+              Some(Input::Start) | None => {}
+            }
+
+            if *is_paused {
+              continue;
+            }
+
+            if *next == files.len() {
+              let _ = output
+                .send(Event::Finished {
+                  indexed: files.len(),
+                })
+                .await;
+              state = State::Idle;
+              continue;
+            }
+
+            // Pretend indexing a file takes a while.
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            *next += 1;
+
+            let _ = output
+              .send(Event::Progress {
+                indexed: *next,
+                total: files.len(),
+                current: files[*next - 1].clone(),
+              })
+              .await;
+          }
+          State::Stopped => iced::futures::future::pending().await,
+        }
+      }
+    },
+  )
+}
+
+fn fake_files() -> Vec<String> {
+  (1..=500)
+    .map(|number| format!("src/module_{}/file_{}.rs", number / 25, number))
+    .collect()
+}
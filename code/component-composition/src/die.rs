@@ -0,0 +1,80 @@
+use iced::widget::{Button, Row, Text};
+use iced::{Alignment, Command, Element};
+use rand::Rng;
+
+/// The Messages only this component understands.
+#[derive(Debug, Clone)]
+pub enum Message {
+  Roll,
+  Rolled(u32),
+  Remove,
+}
+
+/// Things that happened inside of the component that the parent may care about.
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+  Rolled(u32),
+  RemoveRequested,
+}
+
+pub struct State {
+  sides: u32,
+  last_roll: Option<u32>,
+  is_rolling: bool,
+}
+
+impl State {
+  pub fn new(sides: u32) -> Self {
+    State {
+      sides,
+      last_roll: None,
+      is_rolling: false,
+    }
+  }
+
+  pub fn clear(&mut self) {
+    self.last_roll = None;
+  }
+
+  /// Like Application::update, but the component may also report an Event to its parent.
+  pub fn update(&mut self, message: Message) -> (Command<Message>, Option<Event>) {
+    match message {
+      Message::Roll => {
+        self.is_rolling = true;
+        (Command::perform(roll(self.sides), Message::Rolled), None)
+      }
+      Message::Rolled(value) => {
+        self.is_rolling = false;
+        self.last_roll = Some(value);
+        (Command::none(), Some(Event::Rolled(value)))
+      }
+      Message::Remove => (Command::none(), Some(Event::RemoveRequested)),
+    }
+  }
+
+  /// The view only knows about this component's Message. The parent decides how to wrap it.
+  pub fn view(&self) -> Element<Message> {
+    let last_roll = match self.last_roll {
+      Some(value) => value.to_string(),
+      None => String::from("-"),
+    };
+
+    Row::with_children(vec![
+      Text::new(format!("d{}", self.sides)).width(50).into(),
+      Text::new(last_roll).width(50).into(),
+      Button::new("Roll")
+        .on_press_maybe((!self.is_rolling).then_some(Message::Roll))
+        .into(),
+      Button::new("Remove").on_press(Message::Remove).into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center)
+    .into()
+  }
+}
+
+/// Pretend rolling is slow so the component has a Command of its own to hand back to the parent.
+async fn roll(sides: u32) -> u32 {
+  tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+  rand::thread_rng().gen_range(1..=sides)
+}
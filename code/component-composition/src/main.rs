@@ -0,0 +1,135 @@
+mod die;
+mod tally;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{Button, Column, Container, Row, Rule, Scrollable, Text};
+use iced::{executor, Alignment, Application, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  DiceTable::run(Settings::default())
+}
+
+/// The parent Message wraps the Messages of its children.
+#[derive(Debug, Clone)]
+pub enum Message {
+  AddDie(u32),
+  Die(usize, die::Message),
+  Tally(tally::Message),
+}
+
+struct DiceTable {
+  // Dice can be removed while a roll is in flight, so they are addressed by id instead of by index.
+  dice: Vec<(usize, die::State)>,
+  next_die_id: usize,
+  tally: tally::State,
+}
+
+impl Application for DiceTable {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      DiceTable {
+        dice: vec![(0, die::State::new(6))],
+        next_die_id: 1,
+        tally: tally::State::default(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Component Composition")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::AddDie(sides) => {
+        self.dice.push((self.next_die_id, die::State::new(sides)));
+        self.next_die_id += 1;
+
+        Command::none()
+      }
+      Message::Die(id, message) => {
+        let Some((_, die)) = self.dice.iter_mut().find(|(die_id, _)| *die_id == id) else {
+          return Command::none();
+        };
+
+        let (command, event) = die.update(message);
+
+        // React to what happened inside of the child.
+        match event {
+          Some(die::Event::Rolled(value)) => self.tally.record(value),
+          Some(die::Event::RemoveRequested) => self.dice.retain(|(die_id, _)| *die_id != id),
+          None => {}
+        }
+
+        // The child's Command produces die::Messages, wrap them so they find their way back here.
+        command.map(move |message| Message::Die(id, message))
+      }
+      Message::Tally(message) => {
+        if let Some(tally::Event::Cleared) = self.tally.update(message) {
+          for (_, die) in &mut self.dice {
+            die.clear();
+          }
+        }
+
+        Command::none()
+      }
+    }
+  }
+
+  fn view(&self) -> Element<Message> {
+    let add_dice = Row::with_children(
+      [4, 6, 8, 12, 20]
+        .into_iter()
+        .map(|sides| {
+          Button::new(Text::new(format!("Add d{}", sides)))
+            .on_press(Message::AddDie(sides))
+            .into()
+        })
+        .collect(),
+    )
+    .spacing(10);
+
+    // Each child renders an Element<die::Message>, map turns it into an Element<Message>.
+    let dice = self
+      .dice
+      .iter()
+      .map(|(id, die)| {
+        let id = *id;
+        die.view().map(move |message| Message::Die(id, message))
+      })
+      .collect();
+
+    let table = Column::with_children(vec![
+      add_dice.into(),
+      Scrollable::new(Column::with_children(dice).spacing(10)).into(),
+    ])
+    .spacing(20)
+    .width(Length::Fill);
+
+    let row = Row::with_children(vec![
+      table.into(),
+      Rule::vertical(10).into(),
+      self.tally.view().map(Message::Tally),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Start);
+
+    Container::new(row)
+      .align_x(Horizontal::Center)
+      .align_y(Vertical::Top)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
@@ -0,0 +1,49 @@
+use iced::widget::{Button, Column, Text};
+use iced::{Alignment, Element};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Clear,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+  Cleared,
+}
+
+#[derive(Default)]
+pub struct State {
+  rolls: Vec<u32>,
+}
+
+impl State {
+  /// Parents can also talk to their children directly through methods.
+  pub fn record(&mut self, value: u32) {
+    self.rolls.push(value);
+  }
+
+  /// This component never needs a Command, so update only returns an optional Event.
+  pub fn update(&mut self, message: Message) -> Option<Event> {
+    match message {
+      Message::Clear => {
+        self.rolls.clear();
+        Some(Event::Cleared)
+      }
+    }
+  }
+
+  pub fn view(&self) -> Element<Message> {
+    let total: u32 = self.rolls.iter().sum();
+
+    Column::with_children(vec![
+      Text::new(format!("Rolls: {}", self.rolls.len())).into(),
+      Text::new(format!("Total: {}", total)).size(32).into(),
+      Button::new("Clear")
+        .on_press_maybe((!self.rolls.is_empty()).then_some(Message::Clear))
+        .into(),
+    ])
+    .spacing(10)
+    .align_items(Alignment::Center)
+    .into()
+  }
+}
@@ -0,0 +1,161 @@
+mod rating;
+
+use iced::keyboard::{self, KeyCode};
+use iced::widget::{focus_next, focus_previous, Column, Container, Row, Text, TextInput};
+use iced::{event, executor, subscription, Alignment, Application, Color, Command, Element, Event, Length, Settings, Subscription, Theme};
+use rating::{Rating, RatingStyle};
+
+pub fn main() -> iced::Result {
+  CustomWidget::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  TitleChanged(String),
+  StoryRated(u8),
+  ActingRated(u8),
+  SpiceRated(u8),
+  FocusNext,
+  FocusPrevious,
+}
+
+/// A style of our own for the Rating, the same way a custom button::StyleSheet is written.
+struct Gold;
+
+impl rating::StyleSheet for Gold {
+  type Style = Theme;
+
+  fn active(&self, style: &Self::Style) -> rating::Appearance {
+    rating::Appearance {
+      filled: Color::from_rgb8(0xF5, 0xC5, 0x18),
+      empty: Color::TRANSPARENT,
+      border_width: 2.0,
+      border_color: Color::from_rgb8(0xC9, 0x9A, 0x00),
+      focus_color: style.palette().primary,
+    }
+  }
+
+  fn hovered(&self, style: &Self::Style) -> rating::Appearance {
+    rating::Appearance {
+      filled: Color::from_rgb8(0xFF, 0xDD, 0x66),
+      ..self.active(style)
+    }
+  }
+}
+
+struct CustomWidget {
+  title: String,
+  story: u8,
+  acting: u8,
+  spice: u8,
+}
+
+impl Application for CustomWidget {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      CustomWidget {
+        title: String::new(),
+        story: 3,
+        acting: 0,
+        spice: 1,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Custom Widget")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::TitleChanged(title) => self.title = title,
+      // Like a Slider, the Rating only reports the new value. Storing it is up to the Application.
+      Message::StoryRated(value) => self.story = value,
+      Message::ActingRated(value) => self.acting = value,
+      Message::SpiceRated(value) => self.spice = value,
+      // The focus operations visit every widget that calls operation.focusable, built-in or not.
+      Message::FocusNext => return focus_next(),
+      Message::FocusPrevious => return focus_previous(),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let content = Column::with_children(vec![
+      Text::new("Rate a movie").size(32).into(),
+      TextInput::new("Title", &self.title)
+        .on_input(Message::TitleChanged)
+        .width(300)
+        .into(),
+      labeled(
+        "Story",
+        self.story,
+        Rating::new(5, self.story, Message::StoryRated).into(),
+      ),
+      labeled(
+        "Acting",
+        self.acting,
+        Rating::new(10, self.acting, Message::ActingRated)
+          .size(20.0)
+          .spacing(4.0)
+          .style(RatingStyle::Custom(Box::new(Gold)))
+          .into(),
+      ),
+      labeled(
+        "Spice",
+        self.spice,
+        Rating::new(3, self.spice, Message::SpiceRated)
+          .style(RatingStyle::Danger)
+          .into(),
+      ),
+      Text::new("Click a dot or focus a rating with Tab, then use the arrow keys, Home, End or a number. Escape or clicking elsewhere removes the focus.")
+        .into(),
+    ])
+    .spacing(20);
+
+    Container::new(content)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    // Only Tabs no widget captured, so a widget that wants Tab for itself can still have it.
+    subscription::events_with(|event, status| match (event, status) {
+      (
+        Event::Keyboard(keyboard::Event::KeyPressed {
+          key_code: KeyCode::Tab,
+          modifiers,
+        }),
+        event::Status::Ignored,
+      ) => Some(if modifiers.shift() {
+        Message::FocusPrevious
+      } else {
+        Message::FocusNext
+      }),
+      _ => None,
+    })
+  }
+}
+
+fn labeled<'a>(label: &'a str, value: u8, rating: Element<'a, Message>) -> Element<'a, Message> {
+  Row::with_children(vec![
+    Text::new(label).width(100).into(),
+    rating,
+    Text::new(value.to_string()).into(),
+  ])
+  .spacing(20)
+  .align_items(Alignment::Center)
+  .into()
+}
@@ -0,0 +1,363 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::renderer;
+use iced::advanced::widget::operation::{self, Operation};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::{Id, Widget};
+use iced::advanced::{Clipboard, Shell};
+use iced::keyboard::{self, KeyCode};
+use iced::{event, mouse, Color, Element, Event, Length, Rectangle, Size, Theme};
+
+/// A row of dots to pick a rating from 0 to max with the mouse or the keyboard.
+pub struct Rating<'a, Message, Renderer>
+where
+  Renderer: renderer::Renderer,
+  Renderer::Theme: StyleSheet,
+{
+  id: Option<Id>,
+  max: u8,
+  value: u8,
+  on_change: Box<dyn Fn(u8) -> Message + 'a>,
+  size: f32,
+  spacing: f32,
+  style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Rating<'a, Message, Renderer>
+where
+  Renderer: renderer::Renderer,
+  Renderer::Theme: StyleSheet,
+{
+  pub fn new(max: u8, value: u8, on_change: impl Fn(u8) -> Message + 'a) -> Self {
+    Rating {
+      id: None,
+      max,
+      value: value.min(max),
+      on_change: Box::new(on_change),
+      size: 32.0,
+      spacing: 8.0,
+      style: Default::default(),
+    }
+  }
+
+  /// Sets the Id, so the Rating can be focused with operations like focus_next.
+  pub fn id(mut self, id: Id) -> Self {
+    self.id = Some(id);
+    self
+  }
+
+  /// Sets the diameter of a dot.
+  pub fn size(mut self, size: f32) -> Self {
+    self.size = size;
+    self
+  }
+
+  pub fn spacing(mut self, spacing: f32) -> Self {
+    self.spacing = spacing;
+    self
+  }
+
+  pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+    self.style = style.into();
+    self
+  }
+
+  /// The bounds of a dot, with index 0 being the leftmost one.
+  fn dot_bounds(&self, bounds: Rectangle, index: u8) -> Rectangle {
+    Rectangle {
+      x: bounds.x + index as f32 * (self.size + self.spacing),
+      y: bounds.y,
+      width: self.size,
+      height: self.size,
+    }
+  }
+
+  /// The rating the cursor points at, if it is over the widget.
+  fn value_at(&self, bounds: Rectangle, cursor: mouse::Cursor) -> Option<u8> {
+    let position = cursor.position_in(bounds)?;
+    let index = (position.x / (self.size + self.spacing)) as u8;
+
+    Some(index.saturating_add(1).min(self.max))
+  }
+}
+
+/// The part of the widget that lives in the Tree, so it survives view() being called again.
+#[derive(Debug, Default)]
+struct State {
+  is_focused: bool,
+}
+
+/// Implementing Focusable lets the focus operations, like focus_next, find and focus the widget.
+impl operation::Focusable for State {
+  fn is_focused(&self) -> bool {
+    self.is_focused
+  }
+
+  fn focus(&mut self) {
+    self.is_focused = true;
+  }
+
+  fn unfocus(&mut self) {
+    self.is_focused = false;
+  }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Rating<'a, Message, Renderer>
+where
+  Renderer: renderer::Renderer,
+  Renderer::Theme: StyleSheet,
+{
+  fn tag(&self) -> tree::Tag {
+    tree::Tag::of::<State>()
+  }
+
+  fn state(&self) -> tree::State {
+    tree::State::new(State::default())
+  }
+
+  fn width(&self) -> Length {
+    Length::Shrink
+  }
+
+  fn height(&self) -> Length {
+    Length::Shrink
+  }
+
+  fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    let width = self.max as f32 * self.size + self.max.saturating_sub(1) as f32 * self.spacing;
+    let limits = limits.width(self.width()).height(self.height());
+
+    layout::Node::new(limits.resolve(Size::new(width, self.size)))
+  }
+
+  fn operate(&self, tree: &mut Tree, _layout: Layout<'_>, _renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    let state = tree.state.downcast_mut::<State>();
+
+    operation.focusable(state, self.id.as_ref());
+  }
+
+  fn on_event(
+    &mut self,
+    tree: &mut Tree,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    _renderer: &Renderer,
+    _clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    _viewport: &Rectangle,
+  ) -> event::Status {
+    let state = tree.state.downcast_mut::<State>();
+
+    match event {
+      Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+        // Clicking anywhere else takes the focus away, like it does for a TextInput.
+        let Some(value) = self.value_at(layout.bounds(), cursor) else {
+          state.is_focused = false;
+          return event::Status::Ignored;
+        };
+
+        state.is_focused = true;
+        shell.publish((self.on_change)(value));
+
+        event::Status::Captured
+      }
+      Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if state.is_focused => {
+        let value = match key_code {
+          KeyCode::Left | KeyCode::Down => self.value.saturating_sub(1),
+          KeyCode::Right | KeyCode::Up => self.value.saturating_add(1).min(self.max),
+          KeyCode::Home => 0,
+          KeyCode::End => self.max,
+          KeyCode::Escape => {
+            state.is_focused = false;
+            return event::Status::Captured;
+          }
+          key_code => match digit(key_code) {
+            Some(digit) if digit <= self.max => digit,
+            // Leave other keys, like Tab, to the rest of the application.
+            _ => return event::Status::Ignored,
+          },
+        };
+
+        if value != self.value {
+          shell.publish((self.on_change)(value));
+        }
+
+        event::Status::Captured
+      }
+      _ => event::Status::Ignored,
+    }
+  }
+
+  fn mouse_interaction(&self, _tree: &Tree, layout: Layout<'_>, cursor: mouse::Cursor, _viewport: &Rectangle, _renderer: &Renderer) -> mouse::Interaction {
+    if cursor.is_over(layout.bounds()) {
+      mouse::Interaction::Pointer
+    } else {
+      mouse::Interaction::default()
+    }
+  }
+
+  fn draw(
+    &self,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    _style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    _viewport: &Rectangle,
+  ) {
+    let state = tree.state.downcast_ref::<State>();
+    let bounds = layout.bounds();
+    let hovered = self.value_at(bounds, cursor);
+
+    let appearance = if hovered.is_some() {
+      theme.hovered(&self.style)
+    } else if state.is_focused {
+      theme.focused(&self.style)
+    } else {
+      theme.active(&self.style)
+    };
+
+    // While hovering, show what a click would set the rating to.
+    let shown = hovered.unwrap_or(self.value);
+
+    for index in 0..self.max {
+      let color = if index < shown {
+        appearance.filled
+      } else {
+        appearance.empty
+      };
+
+      renderer.fill_quad(
+        renderer::Quad {
+          bounds: self.dot_bounds(bounds, index),
+          border_radius: (self.size / 2.0).into(),
+          border_width: appearance.border_width,
+          border_color: appearance.border_color,
+        },
+        color,
+      );
+    }
+
+    if state.is_focused {
+      renderer.fill_quad(
+        renderer::Quad {
+          bounds: Rectangle {
+            x: bounds.x - 4.0,
+            y: bounds.y - 4.0,
+            width: bounds.width + 8.0,
+            height: bounds.height + 8.0,
+          },
+          border_radius: ((self.size + 8.0) / 2.0).into(),
+          border_width: 2.0,
+          border_color: appearance.focus_color,
+        },
+        Color::TRANSPARENT,
+      );
+    }
+  }
+}
+
+impl<'a, Message, Renderer> From<Rating<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+  Message: 'a,
+  Renderer: renderer::Renderer + 'a,
+  Renderer::Theme: StyleSheet,
+{
+  fn from(rating: Rating<'a, Message, Renderer>) -> Self {
+    Element::new(rating)
+  }
+}
+
+fn digit(key_code: KeyCode) -> Option<u8> {
+  match key_code {
+    KeyCode::Key0 | KeyCode::Numpad0 => Some(0),
+    KeyCode::Key1 | KeyCode::Numpad1 => Some(1),
+    KeyCode::Key2 | KeyCode::Numpad2 => Some(2),
+    KeyCode::Key3 | KeyCode::Numpad3 => Some(3),
+    KeyCode::Key4 | KeyCode::Numpad4 => Some(4),
+    KeyCode::Key5 | KeyCode::Numpad5 => Some(5),
+    KeyCode::Key6 | KeyCode::Numpad6 => Some(6),
+    KeyCode::Key7 | KeyCode::Numpad7 => Some(7),
+    KeyCode::Key8 | KeyCode::Numpad8 => Some(8),
+    KeyCode::Key9 | KeyCode::Numpad9 => Some(9),
+    _ => None,
+  }
+}
+
+/// How a Rating looks in one of its states.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+  pub filled: Color,
+  pub empty: Color,
+  pub border_width: f32,
+  pub border_color: Color,
+  pub focus_color: Color,
+}
+
+/// The same shape as the StyleSheets of the built-in widgets, so a theme styles a Rating like anything else.
+pub trait StyleSheet {
+  type Style: Default;
+
+  fn active(&self, style: &Self::Style) -> Appearance;
+
+  fn hovered(&self, style: &Self::Style) -> Appearance;
+
+  /// Defaults to the active Appearance, the focus ring is drawn either way.
+  fn focused(&self, style: &Self::Style) -> Appearance {
+    self.active(style)
+  }
+}
+
+/// The styles the built-in Theme offers for a Rating.
+#[derive(Default)]
+pub enum RatingStyle {
+  #[default]
+  Primary,
+  Danger,
+  Custom(Box<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StyleSheet for Theme {
+  type Style = RatingStyle;
+
+  fn active(&self, style: &Self::Style) -> Appearance {
+    let palette = self.extended_palette();
+
+    let filled = match style {
+      RatingStyle::Primary => palette.primary.strong.color,
+      RatingStyle::Danger => palette.danger.strong.color,
+      RatingStyle::Custom(custom) => return custom.active(self),
+    };
+
+    Appearance {
+      filled,
+      empty: palette.background.weak.color,
+      border_width: 1.0,
+      border_color: palette.background.strong.color,
+      focus_color: palette.primary.base.color,
+    }
+  }
+
+  fn hovered(&self, style: &Self::Style) -> Appearance {
+    let palette = self.extended_palette();
+
+    let filled = match style {
+      RatingStyle::Primary => palette.primary.base.color,
+      RatingStyle::Danger => palette.danger.base.color,
+      RatingStyle::Custom(custom) => return custom.hovered(self),
+    };
+
+    Appearance {
+      filled,
+      ..self.active(style)
+    }
+  }
+
+  fn focused(&self, style: &Self::Style) -> Appearance {
+    match style {
+      RatingStyle::Custom(custom) => custom.focused(self),
+      _ => self.active(style),
+    }
+  }
+}
@@ -0,0 +1,391 @@
+mod watcher;
+
+use iced::widget::{Button, Column, Container, PickList, Row, Rule, Scrollable, Text, TextInput, Toggler};
+use iced::{executor, theme, Alignment, Application, Color, Command, Element, Length, Settings, Subscription, Theme};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+extern crate chrono;
+
+pub fn main() -> iced::Result {
+  FileBrowser::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Loaded(PathBuf, Result<Vec<Entry>, String>),
+  Watcher(watcher::Event),
+  Open(PathBuf),
+  Up,
+  Select(PathBuf),
+  FilterChanged(String),
+  SortBy(SortBy),
+  ToggleAscending(bool),
+  ToggleHidden(bool),
+}
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+  name: String,
+  path: PathBuf,
+  is_dir: bool,
+  size: u64,
+  modified: Option<SystemTime>,
+  is_read_only: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+  Name,
+  Size,
+  Modified,
+}
+
+impl Display for SortBy {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SortBy::Name => write!(f, "Name"),
+      SortBy::Size => write!(f, "Size"),
+      SortBy::Modified => write!(f, "Modified"),
+    }
+  }
+}
+
+const ALL_SORT_BY: [SortBy; 3] = [SortBy::Name, SortBy::Size, SortBy::Modified];
+
+struct FileBrowser {
+  path: PathBuf,
+  entries: Vec<Entry>,
+  is_loading: bool,
+  error: Option<String>,
+  /// Why the directory isn't being watched. The listing is still valid, it just won't update on its own.
+  watcher_error: Option<String>,
+  selected: Option<PathBuf>,
+  filter: String,
+  sort_by: SortBy,
+  is_ascending: bool,
+  show_hidden: bool,
+}
+
+impl FileBrowser {
+  fn load(&mut self, path: PathBuf) -> Command<Message> {
+    self.is_loading = true;
+    Command::perform(read_directory(path.clone()), move |result| {
+      Message::Loaded(path, result)
+    })
+  }
+
+  /// The entries to display, filtered and sorted. Directories always come first.
+  fn visible_entries(&self) -> Vec<&Entry> {
+    let filter = self.filter.to_lowercase();
+
+    let mut entries: Vec<&Entry> = self
+      .entries
+      .iter()
+      .filter(|entry| self.show_hidden || !entry.name.starts_with('.'))
+      .filter(|entry| entry.name.to_lowercase().contains(&filter))
+      .collect();
+
+    entries.sort_by(|a, b| {
+      let ordering = match self.sort_by {
+        SortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortBy::Size => a.size.cmp(&b.size),
+        SortBy::Modified => a.modified.cmp(&b.modified),
+      };
+
+      let ordering = if self.is_ascending {
+        ordering
+      } else {
+        ordering.reverse()
+      };
+
+      b.is_dir.cmp(&a.is_dir).then(ordering)
+    });
+
+    entries
+  }
+}
+
+impl Application for FileBrowser {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    let path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+
+    let mut file_browser = FileBrowser {
+      path: path.clone(),
+      entries: Vec::new(),
+      is_loading: false,
+      error: None,
+      watcher_error: None,
+      selected: None,
+      filter: String::new(),
+      sort_by: SortBy::Name,
+      is_ascending: true,
+      show_hidden: false,
+    };
+
+    let command = file_browser.load(path);
+
+    (file_browser, command)
+  }
+
+  fn title(&self) -> String {
+    format!("File Browser - {}", self.path.display())
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Loaded(path, result) => {
+        // The user may have moved on while this directory was loading.
+        if path != self.path {
+          return Command::none();
+        }
+
+        self.is_loading = false;
+
+        match result {
+          Ok(entries) => {
+            self.entries = entries;
+            self.error = None;
+          }
+          Err(error) => self.error = Some(error),
+        }
+      }
+      Message::Watcher(watcher::Event::Changed) => {
+        self.watcher_error = None;
+        return self.load(self.path.clone());
+      }
+      Message::Watcher(watcher::Event::Error(error)) => self.watcher_error = Some(error),
+      Message::Open(path) => {
+        // A new path gets a new watcher.
+        self.watcher_error = None;
+        self.path = path.clone();
+        self.entries.clear();
+        self.selected = None;
+        self.filter.clear();
+        return self.load(path);
+      }
+      Message::Up => {
+        if let Some(parent) = self.path.parent().map(PathBuf::from) {
+          return self.update(Message::Open(parent));
+        }
+      }
+      Message::Select(path) => self.selected = Some(path),
+      Message::FilterChanged(filter) => self.filter = filter,
+      Message::SortBy(sort_by) => self.sort_by = sort_by,
+      Message::ToggleAscending(is_ascending) => self.is_ascending = is_ascending,
+      Message::ToggleHidden(show_hidden) => self.show_hidden = show_hidden,
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let toolbar = Row::with_children(vec![
+      Button::new("Up")
+        .on_press_maybe(self.path.parent().map(|_| Message::Up))
+        .into(),
+      Text::new(self.path.display().to_string())
+        .width(Length::Fill)
+        .into(),
+      TextInput::new("Filter...", &self.filter)
+        .on_input(Message::FilterChanged)
+        .width(200)
+        .into(),
+      PickList::new(ALL_SORT_BY.to_vec(), Some(self.sort_by), Message::SortBy).into(),
+      Toggler::new(
+        String::from("Ascending"),
+        self.is_ascending,
+        Message::ToggleAscending,
+      )
+      .width(Length::Shrink)
+      .into(),
+      Toggler::new(
+        String::from("Hidden"),
+        self.show_hidden,
+        Message::ToggleHidden,
+      )
+      .width(Length::Shrink)
+      .into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center);
+
+    let entries = self
+      .visible_entries()
+      .into_iter()
+      .map(|entry| {
+        let label = if entry.is_dir {
+          format!("{}/", entry.name)
+        } else {
+          entry.name.clone()
+        };
+
+        // Directories open on click, files are selected.
+        let message = if entry.is_dir {
+          Message::Open(entry.path.clone())
+        } else {
+          Message::Select(entry.path.clone())
+        };
+
+        let style = if self.selected.as_ref() == Some(&entry.path) {
+          theme::Button::Primary
+        } else {
+          theme::Button::Text
+        };
+
+        Button::new(Text::new(label))
+          .on_press(message)
+          .style(style)
+          .width(Length::Fill)
+          .into()
+      })
+      .collect();
+
+    let list: Element<Message> = if let Some(error) = &self.error {
+      Text::new(error.as_str())
+        .style(Color::from_rgb(0.8, 0.2, 0.2))
+        .into()
+    } else if self.is_loading && self.entries.is_empty() {
+      Text::new("Loading...").into()
+    } else {
+      Scrollable::new(Column::with_children(entries).spacing(2)).into()
+    };
+
+    let mut list_column = Vec::new();
+
+    if let Some(error) = &self.watcher_error {
+      list_column.push(
+        Container::new(Text::new(format!("Not watching for changes: {}", error)))
+          .width(Length::Fill)
+          .padding(10)
+          .style(theme::Container::Box)
+          .into(),
+      );
+    }
+
+    list_column.push(list);
+
+    let selected = self
+      .selected
+      .as_ref()
+      .and_then(|path| self.entries.iter().find(|entry| &entry.path == path));
+
+    let content = Row::with_children(vec![
+      Container::new(Column::with_children(list_column).spacing(10))
+        .width(Length::FillPortion(2))
+        .height(Length::Fill)
+        .into(),
+      Rule::vertical(10).into(),
+      Container::new(metadata(selected))
+        .width(Length::FillPortion(1))
+        .into(),
+    ])
+    .spacing(20);
+
+    Container::new(
+      Column::with_children(vec![
+        toolbar.into(),
+        Rule::horizontal(10).into(),
+        content.into(),
+      ])
+      .spacing(10),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(20)
+    .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    watcher::watch(self.path.clone()).map(Message::Watcher)
+  }
+}
+
+/// The metadata panel for the selected file.
+fn metadata(entry: Option<&Entry>) -> Element<Message> {
+  let Some(entry) = entry else {
+    return Text::new("Select a file to see its metadata.").into();
+  };
+
+  let modified = match entry.modified {
+    Some(modified) => chrono::DateTime::<chrono::Local>::from(modified)
+      .format("%Y-%m-%d %H:%M:%S")
+      .to_string(),
+    None => String::from("Unknown"),
+  };
+
+  Column::with_children(vec![
+    Text::new(entry.name.as_str()).size(24).into(),
+    Text::new(format!("Path: {}", entry.path.display())).into(),
+    Text::new(format!("Size: {}", format_size(entry.size))).into(),
+    Text::new(format!("Modified: {}", modified)).into(),
+    Text::new(format!("Read only: {}", entry.is_read_only)).into(),
+  ])
+  .spacing(10)
+  .into()
+}
+
+fn format_size(size: u64) -> String {
+  const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+  let mut size = size as f64;
+  let mut unit = 0;
+
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+
+  if unit == 0 {
+    format!("{} {}", size, UNITS[unit])
+  } else {
+    format!("{:.1} {}", size, UNITS[unit])
+  }
+}
+
+/// Reads the entries of a directory without blocking the UI.
+async fn read_directory(path: PathBuf) -> Result<Vec<Entry>, String> {
+  let mut read_dir = tokio::fs::read_dir(&path)
+    .await
+    .map_err(|error| format!("Can't read {}: {}", path.display(), error))?;
+
+  let mut entries = Vec::new();
+
+  while let Some(dir_entry) = read_dir
+    .next_entry()
+    .await
+    .map_err(|error| error.to_string())?
+  {
+    // tokio::fs::metadata follows symlinks, unlike DirEntry::metadata, so a link to a directory can be opened. A broken
+    // link has nothing to follow and is shown as itself. Entries can also disappear between listing and reading their
+    // metadata. Skip them.
+    let metadata = match tokio::fs::metadata(dir_entry.path()).await {
+      Ok(metadata) => metadata,
+      Err(_) => match dir_entry.metadata().await {
+        Ok(metadata) => metadata,
+        Err(_) => continue,
+      },
+    };
+
+    entries.push(Entry {
+      name: dir_entry.file_name().to_string_lossy().into_owned(),
+      path: dir_entry.path(),
+      is_dir: metadata.is_dir(),
+      size: metadata.len(),
+      modified: metadata.modified().ok(),
+      is_read_only: metadata.permissions().readonly(),
+    });
+  }
+
+  Ok(entries)
+}
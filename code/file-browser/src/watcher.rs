@@ -0,0 +1,54 @@
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, StreamExt};
+use iced::{subscription, Subscription};
+use notify::{RecursiveMode, Watcher};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub enum Event {
+  Changed,
+  Error(String),
+}
+
+/// Reports changes to the entries of a directory.
+///
+/// The path is the id of the Subscription. Returning a different path stops the old watcher and starts a new one.
+pub fn watch(path: PathBuf) -> Subscription<Event> {
+  subscription::channel(path.clone(), 100, |mut output| async move {
+    // notify calls us back on its own thread, so we forward its events through a channel we can await.
+    let (sender, mut receiver) = mpsc::unbounded();
+
+    let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+      let _ = sender.unbounded_send(event);
+    });
+
+    // The watcher stops as soon as it is dropped, so it has to live as long as this future.
+    let _watcher = match watcher.and_then(|mut watcher| {
+      watcher
+        .watch(&path, RecursiveMode::NonRecursive)
+        .map(|_| watcher)
+    }) {
+      Ok(watcher) => watcher,
+      Err(error) => {
+        let _ = output.send(Event::Error(error.to_string())).await;
+        return iced::futures::future::pending().await;
+      }
+    };
+
+    loop {
+      match receiver.select_next_some().await {
+        Ok(_) => {
+          // A single save can produce a burst of events. Wait for it to settle and report it once.
+          tokio::time::sleep(Duration::from_millis(200)).await;
+          while let Ok(Some(_)) = receiver.try_next() {}
+
+          let _ = output.send(Event::Changed).await;
+        }
+        Err(error) => {
+          let _ = output.send(Event::Error(error.to_string())).await;
+        }
+      }
+    }
+  })
+}
@@ -0,0 +1,334 @@
+mod server;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{Button, Column, Container, Row, Text, TextInput};
+use iced::{executor, Alignment, Application, Color, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+  SignUpForm::run(Settings::default())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldId {
+  Name,
+  Email,
+  Password,
+  Age,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  FieldChanged(FieldId, String),
+  Submit,
+  Submitted(Result<(), server::Error>),
+  Reset,
+}
+
+/// A single input and everything we track about it.
+struct Field {
+  value: String,
+  initial: String,
+  /// The user has interacted with the field. Errors are hidden until then so an empty form isn't covered in red.
+  touched: bool,
+  /// An error reported by the server. It is cleared as soon as the value changes.
+  server_error: Option<String>,
+  validate: fn(&str) -> Result<(), String>,
+}
+
+impl Field {
+  fn new(initial: &str, validate: fn(&str) -> Result<(), String>) -> Self {
+    Field {
+      value: initial.to_string(),
+      initial: initial.to_string(),
+      touched: false,
+      server_error: None,
+      validate,
+    }
+  }
+
+  /// The value is different from the last saved value.
+  fn is_dirty(&self) -> bool {
+    self.value != self.initial
+  }
+
+  fn is_valid(&self) -> bool {
+    (self.validate)(&self.value).is_ok()
+  }
+
+  fn error(&self) -> Option<String> {
+    (self.validate)(&self.value)
+      .err()
+      .or_else(|| self.server_error.clone())
+  }
+
+  fn reset(&mut self) {
+    self.value = self.initial.clone();
+    self.touched = false;
+    self.server_error = None;
+  }
+}
+
+struct SignUpForm {
+  name: Field,
+  email: Field,
+  password: Field,
+  age: Field,
+  is_submitting: bool,
+  status: Option<String>,
+}
+
+impl SignUpForm {
+  fn field_mut(&mut self, id: FieldId) -> &mut Field {
+    match id {
+      FieldId::Name => &mut self.name,
+      FieldId::Email => &mut self.email,
+      FieldId::Password => &mut self.password,
+      FieldId::Age => &mut self.age,
+    }
+  }
+
+  fn fields_mut(&mut self) -> [&mut Field; 4] {
+    [
+      &mut self.name,
+      &mut self.email,
+      &mut self.password,
+      &mut self.age,
+    ]
+  }
+
+  fn is_valid(&self) -> bool {
+    self.name.is_valid() && self.email.is_valid() && self.password.is_valid() && self.age.is_valid()
+  }
+
+  fn is_dirty(&self) -> bool {
+    self.name.is_dirty() || self.email.is_dirty() || self.password.is_dirty() || self.age.is_dirty()
+  }
+}
+
+impl Application for SignUpForm {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      SignUpForm {
+        name: Field::new("", validate_name),
+        email: Field::new("", validate_email),
+        password: Field::new("", validate_password),
+        age: Field::new("", validate_age),
+        is_submitting: false,
+        status: None,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Form Validation")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::FieldChanged(id, value) => {
+        let field = self.field_mut(id);
+        field.value = value;
+        field.touched = true;
+        field.server_error = None;
+        self.status = None;
+
+        Command::none()
+      }
+      Message::Submit => {
+        // Pressing enter submits too, so we can get here with an invalid form.
+        if !self.is_valid() || self.is_submitting {
+          for field in self.fields_mut() {
+            field.touched = true;
+          }
+
+          return Command::none();
+        }
+
+        self.is_submitting = true;
+        self.status = None;
+
+        let sign_up = server::SignUp {
+          name: self.name.value.clone(),
+          email: self.email.value.clone(),
+          password: self.password.value.clone(),
+          age: self.age.value.trim().parse().unwrap_or_default(),
+        };
+
+        Command::perform(server::sign_up(sign_up), Message::Submitted)
+      }
+      Message::Submitted(result) => {
+        self.is_submitting = false;
+
+        match result {
+          Ok(()) => {
+            // The submitted values are the new baseline for dirty tracking.
+            for field in self.fields_mut() {
+              field.initial = field.value.clone();
+            }
+            self.status = Some(String::from("Signed up!"));
+          }
+          Err(server::Error::Rejected(errors)) => {
+            // Map each server error back onto the field it belongs to.
+            for (id, error) in errors {
+              let field = self.field_mut(id);
+              field.server_error = Some(error);
+              field.touched = true;
+            }
+            self.status = Some(String::from("The server rejected the form."));
+          }
+        }
+
+        Command::none()
+      }
+      Message::Reset => {
+        for field in self.fields_mut() {
+          field.reset();
+        }
+        self.status = None;
+
+        Command::none()
+      }
+    }
+  }
+
+  fn view(&self) -> Element<Message> {
+    let fields = vec![
+      field_view("Name", FieldId::Name, &self.name, false, self.is_submitting),
+      field_view(
+        "Email",
+        FieldId::Email,
+        &self.email,
+        false,
+        self.is_submitting,
+      ),
+      field_view(
+        "Password",
+        FieldId::Password,
+        &self.password,
+        true,
+        self.is_submitting,
+      ),
+      field_view("Age", FieldId::Age, &self.age, false, self.is_submitting),
+    ];
+
+    let submit_label = if self.is_submitting {
+      "Submitting..."
+    } else {
+      "Submit"
+    };
+
+    let buttons = Row::with_children(vec![
+      Button::new(submit_label)
+        .on_press_maybe((self.is_valid() && !self.is_submitting).then_some(Message::Submit))
+        .into(),
+      Button::new("Reset")
+        .on_press_maybe((self.is_dirty() && !self.is_submitting).then_some(Message::Reset))
+        .into(),
+    ])
+    .spacing(10);
+
+    let mut children = vec![
+      Column::with_children(fields).spacing(20).into(),
+      buttons.into(),
+    ];
+
+    if let Some(status) = &self.status {
+      children.push(Text::new(status.as_str()).into());
+    }
+
+    let column = Column::with_children(children)
+      .spacing(20)
+      .max_width(400)
+      .align_items(Alignment::Start);
+
+    Container::new(column)
+      .align_x(Horizontal::Center)
+      .align_y(Vertical::Center)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+}
+
+/// A label, the input, and the error underneath it.
+fn field_view<'a>(label: &'a str, id: FieldId, field: &'a Field, is_password: bool, is_disabled: bool) -> Element<'a, Message> {
+  // Dirty fields are marked so the user knows what they haven't saved yet.
+  let label = if field.is_dirty() {
+    format!("{} *", label)
+  } else {
+    label.to_string()
+  };
+
+  let mut input = TextInput::new("", &field.value).padding(10);
+
+  // A TextInput without on_input is disabled.
+  if !is_disabled {
+    input = input
+      .on_input(move |value| Message::FieldChanged(id, value))
+      .on_submit(Message::Submit);
+  }
+
+  if is_password {
+    input = input.password();
+  }
+
+  let mut children = vec![Text::new(label).into(), input.into()];
+
+  if field.touched {
+    if let Some(error) = field.error() {
+      children.push(
+        Text::new(error)
+          .size(14)
+          .style(Color::from_rgb(0.8, 0.2, 0.2))
+          .into(),
+      );
+    }
+  }
+
+  Column::with_children(children).spacing(5).into()
+}
+
+fn validate_name(value: &str) -> Result<(), String> {
+  if value.trim().is_empty() {
+    Err(String::from("Name is required."))
+  } else if value.chars().count() > 50 {
+    Err(String::from("Name must be at most 50 characters."))
+  } else {
+    Ok(())
+  }
+}
+
+fn validate_email(value: &str) -> Result<(), String> {
+  match value.split_once('@') {
+    Some((user, domain)) if !user.is_empty() && domain.contains('.') && !domain.ends_with('.') => Ok(()),
+    _ => Err(String::from("Enter a valid email address.")),
+  }
+}
+
+fn validate_password(value: &str) -> Result<(), String> {
+  if value.chars().count() < 8 {
+    Err(String::from("Password must be at least 8 characters."))
+  } else if !value.chars().any(|c| c.is_ascii_digit()) {
+    Err(String::from("Password must contain a number."))
+  } else {
+    Ok(())
+  }
+}
+
+fn validate_age(value: &str) -> Result<(), String> {
+  match value.trim().parse::<u8>() {
+    Ok(age) if (13..=120).contains(&age) => Ok(()),
+    Ok(_) => Err(String::from("Age must be between 13 and 120.")),
+    Err(_) => Err(String::from("Age must be a number.")),
+  }
+}
@@ -0,0 +1,60 @@
+use crate::FieldId;
+
+/// What the form sends to the server.
+#[derive(Debug, Clone)]
+pub struct SignUp {
+  pub name: String,
+  pub email: String,
+  pub password: String,
+  pub age: u8,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  /// The server rejected some of the fields.
+  Rejected(Vec<(FieldId, String)>),
+}
+
+/// A local stand-in for a real API.
+///
+/// Some rules can only be checked by the server, like whether an email address is already registered.
+pub async fn sign_up(sign_up: SignUp) -> Result<(), Error> {
+  tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+  let mut errors = Vec::new();
+
+  if sign_up.name.eq_ignore_ascii_case("admin") {
+    errors.push((FieldId::Name, String::from("This name is reserved.")));
+  }
+
+  if sign_up.email.eq_ignore_ascii_case("taken@example.com") {
+    errors.push((
+      FieldId::Email,
+      String::from("An account with this email already exists."),
+    ));
+  }
+
+  if sign_up
+    .password
+    .to_lowercase()
+    .contains(&sign_up.name.to_lowercase())
+  {
+    errors.push((
+      FieldId::Password,
+      String::from("The password must not contain your name."),
+    ));
+  }
+
+  if sign_up.age < 16 {
+    errors.push((
+      FieldId::Age,
+      String::from("Users under 16 need to sign up with a parent."),
+    ));
+  }
+
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(Error::Rejected(errors))
+  }
+}
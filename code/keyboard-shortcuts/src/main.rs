@@ -0,0 +1,261 @@
+mod shortcuts;
+
+use iced::keyboard::KeyCode;
+use iced::mouse::ScrollDelta;
+use iced::widget::{Column, Container, Row, Rule, Scrollable, Text};
+use iced::{executor, keyboard, mouse, subscription, window, Application, Color, Command, Element, Event, Length, Point, Settings, Subscription, Theme};
+use shortcuts::{Chord, Registry};
+
+pub fn main() -> iced::Result {
+  KeyboardShortcuts::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  EventOccurred(Event),
+  ZoomIn,
+  ZoomOut,
+  ResetZoom,
+  ToggleTheme,
+  ToggleCheatSheet,
+  ClearLog,
+  NewTab,
+}
+
+struct KeyboardShortcuts {
+  shortcuts: Registry<Message>,
+  zoom: f32,
+  theme: Theme,
+  is_cheat_sheet_visible: bool,
+  cursor_position: Option<Point>,
+  window_size: Option<(u32, u32)>,
+  is_focused: bool,
+  log: Vec<String>,
+}
+
+impl KeyboardShortcuts {
+  fn log(&mut self, entry: String) {
+    self.log.push(entry);
+
+    if self.log.len() > 100 {
+      self.log.remove(0);
+    }
+  }
+}
+
+/// The registry is declared once and used for both dispatching and the cheat sheet.
+fn shortcuts() -> Registry<Message> {
+  Registry::new()
+    .register(
+      Chord::new(KeyCode::Equals).ctrl(),
+      "Zoom in",
+      Message::ZoomIn,
+    )
+    .register(
+      Chord::new(KeyCode::Minus).ctrl(),
+      "Zoom out",
+      Message::ZoomOut,
+    )
+    .register(
+      Chord::new(KeyCode::Key0).ctrl(),
+      "Reset zoom",
+      Message::ResetZoom,
+    )
+    .register(
+      Chord::new(KeyCode::T).ctrl().shift(),
+      "Toggle theme",
+      Message::ToggleTheme,
+    )
+    .register(
+      Chord::new(KeyCode::F1),
+      "Toggle cheat sheet",
+      Message::ToggleCheatSheet,
+    )
+    .register(
+      Chord::new(KeyCode::L).ctrl(),
+      "Clear log",
+      Message::ClearLog,
+    )
+    // Deliberately bound twice so the conflict shows up in the cheat sheet.
+    .register(
+      Chord::new(KeyCode::T).ctrl().shift(),
+      "New tab",
+      Message::NewTab,
+    )
+}
+
+impl Application for KeyboardShortcuts {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      KeyboardShortcuts {
+        shortcuts: shortcuts(),
+        zoom: 1.0,
+        theme: Theme::Light,
+        is_cheat_sheet_visible: true,
+        cursor_position: None,
+        window_size: None,
+        is_focused: true,
+        log: Vec::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Keyboard Shortcuts")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::EventOccurred(event) => match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+          key_code,
+          modifiers,
+        }) => {
+          self.log(format!("Key pressed: {:?} {:?}", key_code, modifiers));
+
+          // Turn the key press into an Application Message and handle it like any other.
+          if let Some(message) = self.shortcuts.find(key_code, modifiers) {
+            return self.update(message);
+          }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position }) => self.cursor_position = Some(position),
+        Event::Mouse(mouse::Event::CursorLeft) => self.cursor_position = None,
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+          let (x, y) = match delta {
+            ScrollDelta::Lines { x, y } => (x, y),
+            ScrollDelta::Pixels { x, y } => (x, y),
+          };
+          self.log(format!("Wheel scrolled: {} {}", x, y));
+        }
+        Event::Window(window::Event::Resized { width, height }) => {
+          self.window_size = Some((width, height));
+          self.log(format!("Window resized: {}x{}", width, height));
+        }
+        Event::Window(window::Event::Focused) => self.is_focused = true,
+        Event::Window(window::Event::Unfocused) => self.is_focused = false,
+        event => self.log(format!("{:?}", event)),
+      },
+      Message::ZoomIn => self.zoom = (self.zoom + 0.1).min(3.0),
+      Message::ZoomOut => self.zoom = (self.zoom - 0.1).max(0.5),
+      Message::ResetZoom => self.zoom = 1.0,
+      Message::ToggleTheme => {
+        self.theme = match self.theme {
+          Theme::Light => Theme::Dark,
+          _ => Theme::Light,
+        }
+      }
+      Message::ToggleCheatSheet => self.is_cheat_sheet_visible = !self.is_cheat_sheet_visible,
+      Message::ClearLog => self.log.clear(),
+      Message::NewTab => self.log(String::from(
+        "New tab (never reached, Toggle theme was registered first)",
+      )),
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let cursor_position = match self.cursor_position {
+      Some(position) => format!("Cursor: {:.0}, {:.0}", position.x, position.y),
+      None => String::from("Cursor: outside of the window"),
+    };
+
+    let window_size = match self.window_size {
+      Some((width, height)) => format!("Window: {}x{}", width, height),
+      None => String::from("Window: not resized yet"),
+    };
+
+    let status = Column::with_children(vec![
+      Text::new(format!("Zoom: {:.0}%", self.zoom * 100.0))
+        .size(20.0 * self.zoom)
+        .into(),
+      Text::new(cursor_position).into(),
+      Text::new(window_size).into(),
+      Text::new(if self.is_focused {
+        "Focused"
+      } else {
+        "Unfocused"
+      })
+      .into(),
+    ])
+    .spacing(10);
+
+    let log = Scrollable::new(
+      Column::with_children(
+        self
+          .log
+          .iter()
+          .rev()
+          .map(|entry| Text::new(entry.as_str()).size(14).into())
+          .collect(),
+      )
+      .spacing(5),
+    );
+
+    let mut content = vec![status.into(), Rule::horizontal(10).into(), log.into()];
+
+    if self.is_cheat_sheet_visible {
+      content = vec![Row::with_children(vec![
+        Column::with_children(content)
+          .width(Length::Fill)
+          .spacing(10)
+          .into(),
+        Rule::vertical(10).into(),
+        cheat_sheet(&self.shortcuts),
+      ])
+      .spacing(20)
+      .into()];
+    }
+
+    Container::new(Column::with_children(content).spacing(10))
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    self.theme.clone()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    subscription::events_with(|event, _status| match event {
+      // Redraws happen every frame, they would flood the log.
+      Event::Window(window::Event::RedrawRequested(_)) => None,
+      event => Some(Message::EventOccurred(event)),
+    })
+  }
+}
+
+/// The cheat sheet is generated from the registry so it can never go out of date.
+fn cheat_sheet(shortcuts: &Registry<Message>) -> Element<Message> {
+  let mut children = vec![Text::new("Shortcuts").size(24).into()];
+
+  children.extend(shortcuts.iter().map(|shortcut| {
+    Row::with_children(vec![
+      Text::new(shortcut.chord.to_string()).width(150).into(),
+      Text::new(shortcut.description).into(),
+    ])
+    .into()
+  }));
+
+  for (chord, descriptions) in shortcuts.conflicts() {
+    children.push(
+      Text::new(format!(
+        "Conflict: {} is bound to {}",
+        chord,
+        descriptions.join(", ")
+      ))
+      .style(Color::from_rgb(0.8, 0.2, 0.2))
+      .into(),
+    );
+  }
+
+  Column::with_children(children).spacing(5).width(350).into()
+}
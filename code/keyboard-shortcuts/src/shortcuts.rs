@@ -0,0 +1,122 @@
+use iced::keyboard::{KeyCode, Modifiers};
+use std::fmt::{Display, Formatter};
+
+/// A key pressed together with some modifiers, i.e. Ctrl+Shift+Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chord {
+  key_code: KeyCode,
+  modifiers: Modifiers,
+}
+
+impl Chord {
+  pub fn new(key_code: KeyCode) -> Self {
+    Chord {
+      key_code,
+      modifiers: Modifiers::empty(),
+    }
+  }
+
+  pub fn ctrl(mut self) -> Self {
+    self.modifiers |= Modifiers::CTRL;
+    self
+  }
+
+  pub fn shift(mut self) -> Self {
+    self.modifiers |= Modifiers::SHIFT;
+    self
+  }
+
+  pub fn alt(mut self) -> Self {
+    self.modifiers |= Modifiers::ALT;
+    self
+  }
+}
+
+impl Display for Chord {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    if self.modifiers.control() {
+      write!(f, "Ctrl+")?;
+    }
+    if self.modifiers.alt() {
+      write!(f, "Alt+")?;
+    }
+    if self.modifiers.shift() {
+      write!(f, "Shift+")?;
+    }
+    if self.modifiers.logo() {
+      write!(f, "Super+")?;
+    }
+    write!(f, "{:?}", self.key_code)
+  }
+}
+
+pub struct Shortcut<Message> {
+  pub chord: Chord,
+  pub description: &'static str,
+  message: Message,
+}
+
+/// Every shortcut of the Application in one place.
+///
+/// Because the shortcuts are plain data they can also be checked for conflicts and listed in a cheat sheet.
+pub struct Registry<Message> {
+  shortcuts: Vec<Shortcut<Message>>,
+}
+
+impl<Message> Registry<Message>
+where
+  Message: Clone,
+{
+  pub fn new() -> Self {
+    Registry {
+      shortcuts: Vec::new(),
+    }
+  }
+
+  pub fn register(mut self, chord: Chord, description: &'static str, message: Message) -> Self {
+    self.shortcuts.push(Shortcut {
+      chord,
+      description,
+      message,
+    });
+    self
+  }
+
+  /// Finds the Message bound to a key press.
+  ///
+  /// If a chord is bound more than once the first registration wins.
+  pub fn find(&self, key_code: KeyCode, modifiers: Modifiers) -> Option<Message> {
+    self
+      .shortcuts
+      .iter()
+      .find(|shortcut| shortcut.chord.key_code == key_code && shortcut.chord.modifiers == modifiers)
+      .map(|shortcut| shortcut.message.clone())
+  }
+
+  /// Chords that are bound to more than one shortcut, with the descriptions of every binding.
+  pub fn conflicts(&self) -> Vec<(Chord, Vec<&'static str>)> {
+    let mut conflicts: Vec<(Chord, Vec<&'static str>)> = Vec::new();
+
+    for (index, shortcut) in self.shortcuts.iter().enumerate() {
+      if conflicts.iter().any(|(chord, _)| *chord == shortcut.chord) {
+        continue;
+      }
+
+      let descriptions: Vec<&'static str> = self.shortcuts[index..]
+        .iter()
+        .filter(|other| other.chord == shortcut.chord)
+        .map(|other| other.description)
+        .collect();
+
+      if descriptions.len() > 1 {
+        conflicts.push((shortcut.chord, descriptions));
+      }
+    }
+
+    conflicts
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &Shortcut<Message>> {
+    self.shortcuts.iter()
+  }
+}
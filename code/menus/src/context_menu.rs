@@ -0,0 +1,160 @@
+use crate::menu::{Item, MenuOverlay, Open};
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::{Operation, Widget};
+use iced::advanced::{overlay, renderer, Clipboard, Shell};
+use iced::{event, mouse, Element, Event, Length, Rectangle, Renderer, Theme};
+
+/// Wraps some content and opens a menu where it is right-clicked.
+pub struct ContextMenu<'a, Message> {
+  content: Element<'a, Message>,
+  items: Vec<Item<Message>>,
+}
+
+impl<'a, Message> ContextMenu<'a, Message> {
+  pub fn new(content: impl Into<Element<'a, Message>>, items: Vec<Item<Message>>) -> Self {
+    ContextMenu {
+      content: content.into(),
+      items,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct State {
+  open: Option<Open>,
+}
+
+/// Everything except the right click is passed on to the content, which has its own Tree as the only child.
+impl<'a, Message> Widget<Message, Renderer> for ContextMenu<'a, Message>
+where
+  Message: Clone,
+{
+  fn tag(&self) -> tree::Tag {
+    tree::Tag::of::<State>()
+  }
+
+  fn state(&self) -> tree::State {
+    tree::State::new(State::default())
+  }
+
+  fn children(&self) -> Vec<Tree> {
+    vec![Tree::new(&self.content)]
+  }
+
+  fn diff(&self, tree: &mut Tree) {
+    tree.diff_children(std::slice::from_ref(&self.content));
+  }
+
+  fn width(&self) -> Length {
+    self.content.as_widget().width()
+  }
+
+  fn height(&self) -> Length {
+    self.content.as_widget().height()
+  }
+
+  fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    self.content.as_widget().layout(renderer, limits)
+  }
+
+  fn operate(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    self
+      .content
+      .as_widget()
+      .operate(&mut tree.children[0], layout, renderer, operation);
+  }
+
+  fn on_event(
+    &mut self,
+    tree: &mut Tree,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    viewport: &Rectangle,
+  ) -> event::Status {
+    let status = self.content.as_widget_mut().on_event(
+      &mut tree.children[0],
+      event.clone(),
+      layout,
+      cursor,
+      renderer,
+      clipboard,
+      shell,
+      viewport,
+    );
+
+    if status == event::Status::Captured {
+      return status;
+    }
+
+    match event {
+      Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+          return event::Status::Ignored;
+        };
+
+        tree.state.downcast_mut::<State>().open = Some(Open::new(0, position));
+
+        event::Status::Captured
+      }
+      _ => event::Status::Ignored,
+    }
+  }
+
+  fn mouse_interaction(&self, tree: &Tree, layout: Layout<'_>, cursor: mouse::Cursor, viewport: &Rectangle, renderer: &Renderer) -> mouse::Interaction {
+    self
+      .content
+      .as_widget()
+      .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+  }
+
+  fn draw(
+    &self,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+  ) {
+    self.content.as_widget().draw(
+      &tree.children[0],
+      renderer,
+      theme,
+      style,
+      layout,
+      cursor,
+      viewport,
+    );
+  }
+
+  fn overlay<'b>(&'b mut self, tree: &'b mut Tree, layout: Layout<'_>, renderer: &Renderer) -> Option<overlay::Element<'b, Message, Renderer>> {
+    let state = tree.state.downcast_mut::<State>();
+
+    match state.open.as_ref().map(|open| open.origin) {
+      Some(origin) => Some(overlay::Element::new(
+        origin,
+        Box::new(MenuOverlay::new(&self.items, &mut state.open, None)),
+      )),
+      // While the menu is closed, the content may have overlays of its own, like a PickList.
+      None => self
+        .content
+        .as_widget_mut()
+        .overlay(&mut tree.children[0], layout, renderer),
+    }
+  }
+}
+
+impl<'a, Message> From<ContextMenu<'a, Message>> for Element<'a, Message>
+where
+  Message: Clone + 'a,
+{
+  fn from(context_menu: ContextMenu<'a, Message>) -> Self {
+    Element::new(context_menu)
+  }
+}
@@ -0,0 +1,156 @@
+mod context_menu;
+mod menu;
+mod menu_bar;
+
+use context_menu::ContextMenu;
+use iced::widget::{Column, Container, Text};
+use iced::{executor, theme, window, Application, Color, Command, Element, Length, Settings, Theme};
+use menu::Item;
+use menu_bar::{Menu, MenuBar};
+
+pub fn main() -> iced::Result {
+  Menus::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Action(&'static str),
+  SetTheme(Theme),
+  SetColor(Option<Color>),
+  Quit,
+}
+
+struct Menus {
+  last_action: &'static str,
+  theme: Theme,
+  /// None keeps the Theme's text color.
+  color: Option<Color>,
+}
+
+impl Application for Menus {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      Menus {
+        last_action: "Nothing yet",
+        theme: Theme::Light,
+        color: None,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Menus")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Action(action) => self.last_action = action,
+      Message::SetTheme(theme) => self.theme = theme,
+      Message::SetColor(color) => self.color = color,
+      Message::Quit => return window::close(),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    // The menus are rebuilt on every view(), like any other widget. Only whether they are open is kept.
+    let menu_bar = MenuBar::new(vec![
+      Menu::new(
+        "File",
+        vec![
+          Item::action("New", Message::Action("New")),
+          Item::action("Open...", Message::Action("Open")),
+          Item::submenu(
+            "Open Recent",
+            vec![
+              Item::action("notes.txt", Message::Action("Open notes.txt")),
+              Item::action("todo.md", Message::Action("Open todo.md")),
+              Item::submenu(
+                "Older",
+                vec![
+                  Item::action("2022.txt", Message::Action("Open 2022.txt")),
+                  Item::action("2021.txt", Message::Action("Open 2021.txt")),
+                ],
+              ),
+            ],
+          ),
+          Item::action("Quit", Message::Quit),
+        ],
+      ),
+      Menu::new(
+        "Edit",
+        vec![
+          Item::action("Undo", Message::Action("Undo")),
+          Item::action("Redo", Message::Action("Redo")),
+          Item::action("Cut", Message::Action("Cut")),
+          Item::action("Copy", Message::Action("Copy")),
+          Item::action("Paste", Message::Action("Paste")),
+        ],
+      ),
+      Menu::new(
+        "View",
+        vec![Item::submenu(
+          "Theme",
+          vec![
+            Item::action("Light", Message::SetTheme(Theme::Light)),
+            Item::action("Dark", Message::SetTheme(Theme::Dark)),
+          ],
+        )],
+      ),
+    ]);
+
+    let canvas = Container::new(
+      Column::with_children(vec![
+        Text::new("Right-click anywhere in this area.")
+          .style(self.color.map_or(theme::Text::Default, theme::Text::Color))
+          .into(),
+        Text::new("Right-click close to the right or bottom edge, the menu moves to stay inside the window.").into(),
+        Text::new("Open menus can be used with the arrow keys, Enter and Escape.").into(),
+        Text::new(format!("Last action: {}", self.last_action)).into(),
+      ])
+      .spacing(10),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(20)
+    .style(theme::Container::Box);
+
+    let context_menu = ContextMenu::new(
+      canvas,
+      vec![
+        Item::action("Copy", Message::Action("Copy")),
+        Item::action("Paste", Message::Action("Paste")),
+        Item::submenu(
+          "Text color",
+          vec![
+            Item::action("Default", Message::SetColor(None)),
+            Item::action(
+              "Red",
+              Message::SetColor(Some(Color::from_rgb(0.8, 0.2, 0.2))),
+            ),
+            Item::action(
+              "Blue",
+              Message::SetColor(Some(Color::from_rgb(0.2, 0.3, 0.8))),
+            ),
+          ],
+        ),
+      ],
+    );
+
+    Column::with_children(vec![
+      menu_bar.into(),
+      Container::new(context_menu).padding(20).into(),
+    ])
+    .into()
+  }
+
+  fn theme(&self) -> Theme {
+    self.theme.clone()
+  }
+}
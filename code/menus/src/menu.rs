@@ -0,0 +1,420 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::overlay::Overlay;
+use iced::advanced::text::{self, LineHeight, Renderer as _, Shaping};
+use iced::advanced::{renderer, Clipboard, Renderer as _, Shell};
+use iced::alignment::{Horizontal, Vertical};
+use iced::keyboard::{self, KeyCode};
+use iced::{event, mouse, Event, Point, Rectangle, Renderer, Size, Theme};
+
+pub const TEXT_SIZE: f32 = 16.0;
+pub const ITEM_HEIGHT: f32 = 28.0;
+/// The space left and right of a label.
+pub const HORIZONTAL_PADDING: f32 = 12.0;
+/// The space above the first and below the last item of a menu.
+const VERTICAL_PADDING: f32 = 4.0;
+/// Room for the arrow of a submenu.
+const ARROW_WIDTH: f32 = 24.0;
+const MIN_WIDTH: f32 = 120.0;
+pub const BORDER_RADIUS: f32 = 4.0;
+
+/// An entry of a menu. It either produces a Message or opens another menu.
+pub enum Item<Message> {
+  Action(String, Message),
+  Submenu(String, Vec<Item<Message>>),
+}
+
+impl<Message> Item<Message> {
+  pub fn action(label: impl Into<String>, message: Message) -> Self {
+    Item::Action(label.into(), message)
+  }
+
+  pub fn submenu(label: impl Into<String>, items: Vec<Item<Message>>) -> Self {
+    Item::Submenu(label.into(), items)
+  }
+
+  fn label(&self) -> &str {
+    match self {
+      Item::Action(label, _) | Item::Submenu(label, _) => label,
+    }
+  }
+}
+
+/// Kept in the tree::State of the widget that owns the menu, so it stays open between calls to view().
+#[derive(Debug)]
+pub struct Open {
+  /// Which of the widget's menus is open. A ContextMenu only has one.
+  pub menu: usize,
+  /// Where the top left corner of the menu wants to be.
+  pub origin: Point,
+  /// The highlighted item of every open level, starting at the top. Highlighting a submenu opens it.
+  pub selection: Vec<usize>,
+}
+
+impl Open {
+  pub fn new(menu: usize, origin: Point) -> Self {
+    Open {
+      menu,
+      origin,
+      selection: Vec::new(),
+    }
+  }
+}
+
+/// The items of every level that is visible: the menu itself, then one list per highlighted submenu.
+fn levels<'a, Message>(items: &'a [Item<Message>], selection: &[usize]) -> Vec<&'a [Item<Message>]> {
+  let mut levels = vec![items];
+
+  for (level, index) in selection.iter().enumerate() {
+    match levels[level].get(*index) {
+      Some(Item::Submenu(_, children)) => levels.push(children),
+      _ => break,
+    }
+  }
+
+  levels
+}
+
+fn menu_size<Message>(renderer: &Renderer, items: &[Item<Message>]) -> Size {
+  let label_width = items
+    .iter()
+    .map(|item| {
+      renderer.measure_width(
+        item.label(),
+        TEXT_SIZE,
+        renderer.default_font(),
+        Shaping::Basic,
+      )
+    })
+    .fold(0.0, f32::max);
+  let has_submenu = items.iter().any(|item| matches!(item, Item::Submenu(..)));
+  let arrow_width = if has_submenu { ARROW_WIDTH } else { 0.0 };
+
+  Size::new(
+    (label_width + 2.0 * HORIZONTAL_PADDING + arrow_width).max(MIN_WIDTH),
+    items.len() as f32 * ITEM_HEIGHT + 2.0 * VERTICAL_PADDING,
+  )
+}
+
+fn item_bounds(menu: Rectangle, index: usize) -> Rectangle {
+  Rectangle {
+    x: menu.x,
+    y: menu.y + VERTICAL_PADDING + index as f32 * ITEM_HEIGHT,
+    width: menu.width,
+    height: ITEM_HEIGHT,
+  }
+}
+
+/// Moves a start position back by the overflow, so something of this size stays inside the window.
+fn fit(start: f32, length: f32, available: f32) -> f32 {
+  start.min(available - length).max(0.0)
+}
+
+pub fn draw_label(renderer: &mut Renderer, content: &str, bounds: Rectangle, color: iced::Color, horizontal_alignment: Horizontal) {
+  let x = match horizontal_alignment {
+    Horizontal::Left => bounds.x + HORIZONTAL_PADDING,
+    Horizontal::Center => bounds.center_x(),
+    Horizontal::Right => bounds.x + bounds.width - HORIZONTAL_PADDING,
+  };
+  let font = renderer.default_font();
+
+  renderer.fill_text(text::Text {
+    content,
+    bounds: Rectangle {
+      x,
+      y: bounds.center_y(),
+      ..bounds
+    },
+    size: TEXT_SIZE,
+    line_height: LineHeight::default(),
+    color,
+    font,
+    horizontal_alignment,
+    vertical_alignment: Vertical::Center,
+    shaping: Shaping::Basic,
+  });
+}
+
+/// The open menu and its submenus, drawn on top of everything else.
+///
+/// The overlay borrows the owner's Open, so closing the menu is setting it to None.
+pub struct MenuOverlay<'a, Message> {
+  items: &'a [Item<Message>],
+  open: &'a mut Option<Open>,
+  /// Clicks here are left to the owner, so the MenuBar can close a menu by clicking its title again.
+  passthrough: Option<Rectangle>,
+}
+
+impl<'a, Message> MenuOverlay<'a, Message> {
+  pub fn new(items: &'a [Item<Message>], open: &'a mut Option<Open>, passthrough: Option<Rectangle>) -> Self {
+    MenuOverlay {
+      items,
+      open,
+      passthrough,
+    }
+  }
+
+  fn selection(&self) -> &[usize] {
+    match self.open.as_ref() {
+      Some(open) => &open.selection,
+      None => &[],
+    }
+  }
+
+  /// The level and index of the item under the cursor.
+  fn item_at(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, usize)> {
+    let levels = levels(self.items, self.selection());
+
+    layout
+      .children()
+      .zip(levels)
+      .enumerate()
+      .find_map(|(level, (menu, items))| {
+        let position = cursor.position_in(menu.bounds())?;
+        let index = ((position.y - VERTICAL_PADDING) / ITEM_HEIGHT).floor();
+
+        (index >= 0.0 && (index as usize) < items.len()).then_some((level, index as usize))
+      })
+  }
+
+  fn is_over_menu(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+    layout
+      .children()
+      .any(|menu| menu.bounds().contains(cursor_position))
+  }
+
+  /// Runs the highlighted item of the deepest level: a Message for an action, one level deeper for a submenu.
+  fn activate(&mut self, shell: &mut Shell<'_, Message>)
+  where
+    Message: Clone,
+  {
+    let Some(open) = self.open.as_mut() else {
+      return;
+    };
+    let levels = levels(self.items, &open.selection);
+
+    let Some(index) = open.selection.last().copied() else {
+      return;
+    };
+
+    match levels[open.selection.len() - 1].get(index) {
+      Some(Item::Action(_, message)) => {
+        shell.publish(message.clone());
+        *self.open = None;
+      }
+      Some(Item::Submenu(_, children)) if !children.is_empty() => open.selection.push(0),
+      _ => {}
+    }
+  }
+
+  fn on_key(&mut self, key_code: KeyCode, shell: &mut Shell<'_, Message>)
+  where
+    Message: Clone,
+  {
+    let Some(open) = self.open.as_mut() else {
+      return;
+    };
+    let levels = levels(self.items, &open.selection);
+    // The level the arrow keys move in. The submenu of its highlighted item is only shown until Right enters it.
+    let active = open.selection.len().saturating_sub(1);
+    let count = levels[active].len();
+
+    match key_code {
+      KeyCode::Down if count > 0 => match open.selection.last_mut() {
+        Some(index) => *index = (*index + 1) % count,
+        None => open.selection.push(0),
+      },
+      KeyCode::Up if count > 0 => match open.selection.last_mut() {
+        Some(index) => *index = (*index + count - 1) % count,
+        None => open.selection.push(count - 1),
+      },
+      KeyCode::Right => {
+        let index = open.selection.last().copied();
+
+        if let Some(Item::Submenu(_, children)) = index.and_then(|index| levels[active].get(index)) {
+          if !children.is_empty() {
+            open.selection.push(0);
+          }
+        }
+      }
+      KeyCode::Left if open.selection.len() > 1 => {
+        open.selection.pop();
+      }
+      KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => self.activate(shell),
+      KeyCode::Escape => {
+        if open.selection.len() > 1 {
+          open.selection.pop();
+        } else {
+          *self.open = None;
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'a, Message> Overlay<Message, Renderer> for MenuOverlay<'a, Message>
+where
+  Message: Clone,
+{
+  fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+    let selection = self.selection();
+    let mut menus: Vec<layout::Node> = Vec::new();
+
+    for (level, items) in levels(self.items, selection).into_iter().enumerate() {
+      let size = menu_size(renderer, items);
+
+      let position = match menus.last() {
+        // A menu that doesn't fit is moved back inside the window.
+        None => Point::new(
+          fit(position.x, size.width, bounds.width),
+          fit(position.y, size.height, bounds.height),
+        ),
+        // A submenu opens next to its item, on the right if there is room, else on the left.
+        Some(parent) => {
+          let parent = parent.bounds();
+          let item = item_bounds(parent, selection[level - 1]);
+
+          let x = if parent.x + parent.width + size.width <= bounds.width {
+            parent.x + parent.width
+          } else {
+            (parent.x - size.width).max(0.0)
+          };
+
+          Point::new(
+            x,
+            fit(item.y - VERTICAL_PADDING, size.height, bounds.height),
+          )
+        }
+      };
+
+      let mut menu = layout::Node::new(size);
+      menu.move_to(position);
+      menus.push(menu);
+    }
+
+    layout::Node::with_children(bounds, menus)
+  }
+
+  fn draw(&self, renderer: &mut Renderer, theme: &Theme, _style: &renderer::Style, layout: Layout<'_>, _cursor: mouse::Cursor) {
+    let palette = theme.extended_palette();
+    let selection = self.selection();
+
+    for (level, (menu, items)) in layout
+      .children()
+      .zip(levels(self.items, selection))
+      .enumerate()
+    {
+      let bounds = menu.bounds();
+
+      renderer.fill_quad(
+        renderer::Quad {
+          bounds,
+          border_radius: BORDER_RADIUS.into(),
+          border_width: 1.0,
+          border_color: palette.background.strong.color,
+        },
+        palette.background.base.color,
+      );
+
+      for (index, item) in items.iter().enumerate() {
+        let item_bounds = item_bounds(bounds, index);
+        let is_highlighted = selection.get(level) == Some(&index);
+
+        let text_color = if is_highlighted {
+          renderer.fill_quad(
+            renderer::Quad {
+              bounds: item_bounds,
+              border_radius: BORDER_RADIUS.into(),
+              border_width: 0.0,
+              border_color: iced::Color::TRANSPARENT,
+            },
+            palette.primary.weak.color,
+          );
+
+          palette.primary.weak.text
+        } else {
+          palette.background.base.text
+        };
+
+        draw_label(
+          renderer,
+          item.label(),
+          item_bounds,
+          text_color,
+          Horizontal::Left,
+        );
+
+        if let Item::Submenu(..) = item {
+          draw_label(renderer, ">", item_bounds, text_color, Horizontal::Right);
+        }
+      }
+    }
+  }
+
+  fn on_event(
+    &mut self,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    _renderer: &Renderer,
+    _clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+  ) -> event::Status {
+    match event {
+      Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+        // Hovering an item highlights it and closes the submenus below it.
+        if let (Some((level, index)), Some(open)) = (self.item_at(layout, cursor), self.open.as_mut()) {
+          open.selection.truncate(level);
+          open.selection.push(index);
+        }
+
+        event::Status::Ignored
+      }
+      Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+        let Some(position) = cursor.position() else {
+          return event::Status::Ignored;
+        };
+
+        if let Some((level, index)) = self.item_at(layout, cursor) {
+          // The click may come without a CursorMoved before it, so the clicked item is selected first.
+          if let Some(open) = self.open.as_mut() {
+            open.selection.truncate(level);
+            open.selection.push(index);
+          }
+
+          self.activate(shell);
+          event::Status::Captured
+        } else if self.is_over_menu(layout, position) {
+          event::Status::Captured
+        } else if self
+          .passthrough
+          .is_some_and(|bounds| bounds.contains(position))
+        {
+          event::Status::Ignored
+        } else {
+          // A click anywhere else closes the menu.
+          *self.open = None;
+          event::Status::Ignored
+        }
+      }
+      Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+        self.on_key(key_code, shell);
+        event::Status::Captured
+      }
+      _ => event::Status::Ignored,
+    }
+  }
+
+  fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, _viewport: &Rectangle, _renderer: &Renderer) -> mouse::Interaction {
+    if self.item_at(layout, cursor).is_some() {
+      mouse::Interaction::Pointer
+    } else {
+      mouse::Interaction::default()
+    }
+  }
+
+  /// The layout covers the whole window so the menus can be placed anywhere, but only the menus are really there.
+  fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+    self.is_over_menu(layout, cursor_position)
+  }
+}
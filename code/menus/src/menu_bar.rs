@@ -0,0 +1,236 @@
+use crate::menu::{self, Item, MenuOverlay, Open};
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::text::{Renderer as _, Shaping};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::Widget;
+use iced::advanced::{overlay, renderer, Clipboard, Renderer as _, Shell};
+use iced::alignment::Horizontal;
+use iced::{event, mouse, Element, Event, Length, Point, Rectangle, Renderer, Size, Theme};
+
+/// A title in the MenuBar and the items of the menu it opens.
+pub struct Menu<Message> {
+  title: String,
+  items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+  pub fn new(title: impl Into<String>, items: Vec<Item<Message>>) -> Self {
+    Menu {
+      title: title.into(),
+      items,
+    }
+  }
+}
+
+/// A row of titles along the top of a window, each opening a dropdown menu.
+pub struct MenuBar<Message> {
+  menus: Vec<Menu<Message>>,
+}
+
+impl<Message> MenuBar<Message> {
+  pub fn new(menus: Vec<Menu<Message>>) -> Self {
+    MenuBar { menus }
+  }
+
+  /// Where each title is. They are as wide as their text, one after the other.
+  fn title_bounds(&self, renderer: &Renderer, bounds: Rectangle) -> Vec<Rectangle> {
+    let mut x = bounds.x;
+
+    self
+      .menus
+      .iter()
+      .map(|menu| {
+        let width = renderer.measure_width(
+          &menu.title,
+          menu::TEXT_SIZE,
+          renderer.default_font(),
+          Shaping::Basic,
+        ) + 2.0 * menu::HORIZONTAL_PADDING;
+        let title = Rectangle { x, width, ..bounds };
+
+        x += width;
+        title
+      })
+      .collect()
+  }
+
+  fn title_at(&self, renderer: &Renderer, bounds: Rectangle, cursor: mouse::Cursor) -> Option<(usize, Rectangle)> {
+    let position = cursor.position()?;
+
+    self
+      .title_bounds(renderer, bounds)
+      .into_iter()
+      .enumerate()
+      .find(|(_, title)| title.contains(position))
+  }
+}
+
+#[derive(Debug, Default)]
+struct State {
+  open: Option<Open>,
+}
+
+/// A menu opens below its title.
+fn origin(title: Rectangle) -> Point {
+  Point::new(title.x, title.y + title.height)
+}
+
+impl<Message> Widget<Message, Renderer> for MenuBar<Message>
+where
+  Message: Clone,
+{
+  fn tag(&self) -> tree::Tag {
+    tree::Tag::of::<State>()
+  }
+
+  fn state(&self) -> tree::State {
+    tree::State::new(State::default())
+  }
+
+  fn width(&self) -> Length {
+    Length::Fill
+  }
+
+  fn height(&self) -> Length {
+    Length::Shrink
+  }
+
+  fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    let limits = limits.width(self.width()).height(menu::ITEM_HEIGHT);
+
+    layout::Node::new(limits.resolve(Size::ZERO))
+  }
+
+  fn on_event(
+    &mut self,
+    tree: &mut Tree,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    _clipboard: &mut dyn Clipboard,
+    _shell: &mut Shell<'_, Message>,
+    _viewport: &Rectangle,
+  ) -> event::Status {
+    let state = tree.state.downcast_mut::<State>();
+
+    match event {
+      Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+        // The open menu lets clicks on the whole bar through. One that misses every title closes it, like a click
+        // anywhere else would.
+        let Some((index, title)) = self.title_at(renderer, layout.bounds(), cursor) else {
+          state.open = None;
+          return event::Status::Ignored;
+        };
+
+        // Clicking the title of the open menu closes it again.
+        state.open = match state.open {
+          Some(Open { menu, .. }) if menu == index => None,
+          _ => Some(Open::new(index, origin(title))),
+        };
+
+        event::Status::Captured
+      }
+      Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+        // While a menu is open, moving over another title switches to its menu, like in a desktop menu bar.
+        if let (Some(open), Some((index, title))) = (
+          &state.open,
+          self.title_at(renderer, layout.bounds(), cursor),
+        ) {
+          if open.menu != index {
+            state.open = Some(Open::new(index, origin(title)));
+          }
+        }
+
+        event::Status::Ignored
+      }
+      _ => event::Status::Ignored,
+    }
+  }
+
+  fn mouse_interaction(&self, _tree: &Tree, layout: Layout<'_>, cursor: mouse::Cursor, _viewport: &Rectangle, renderer: &Renderer) -> mouse::Interaction {
+    if self.title_at(renderer, layout.bounds(), cursor).is_some() {
+      mouse::Interaction::Pointer
+    } else {
+      mouse::Interaction::default()
+    }
+  }
+
+  fn draw(
+    &self,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    _style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    _viewport: &Rectangle,
+  ) {
+    let state = tree.state.downcast_ref::<State>();
+    let palette = theme.extended_palette();
+    let bounds = layout.bounds();
+
+    renderer.fill_quad(
+      renderer::Quad {
+        bounds,
+        border_radius: 0.0.into(),
+        border_width: 0.0,
+        border_color: iced::Color::TRANSPARENT,
+      },
+      palette.background.weak.color,
+    );
+
+    let open = state.open.as_ref().map(|open| open.menu);
+    let hovered = self
+      .title_at(renderer, bounds, cursor)
+      .map(|(index, _)| index);
+
+    for (index, (menu, title)) in self
+      .menus
+      .iter()
+      .zip(self.title_bounds(renderer, bounds))
+      .enumerate()
+    {
+      let text_color = if open == Some(index) || hovered == Some(index) {
+        renderer.fill_quad(
+          renderer::Quad {
+            bounds: title,
+            border_radius: menu::BORDER_RADIUS.into(),
+            border_width: 0.0,
+            border_color: iced::Color::TRANSPARENT,
+          },
+          palette.primary.weak.color,
+        );
+
+        palette.primary.weak.text
+      } else {
+        palette.background.weak.text
+      };
+
+      menu::draw_label(renderer, &menu.title, title, text_color, Horizontal::Center);
+    }
+  }
+
+  fn overlay<'a>(&'a mut self, tree: &'a mut Tree, layout: Layout<'_>, _renderer: &Renderer) -> Option<overlay::Element<'a, Message, Renderer>> {
+    let state = tree.state.downcast_mut::<State>();
+    let (menu, origin) = state.open.as_ref().map(|open| (open.menu, open.origin))?;
+
+    Some(overlay::Element::new(
+      origin,
+      Box::new(MenuOverlay::new(
+        &self.menus[menu].items,
+        &mut state.open,
+        Some(layout.bounds()),
+      )),
+    ))
+  }
+}
+
+impl<'a, Message> From<MenuBar<Message>> for Element<'a, Message>
+where
+  Message: Clone + 'a,
+{
+  fn from(menu_bar: MenuBar<Message>) -> Self {
+    Element::new(menu_bar)
+  }
+}
@@ -0,0 +1,260 @@
+use iced::multi_window::Application;
+use iced::widget::{Button, Column, Container, Row, Rule, Text, TextInput};
+use iced::{event, executor, theme, window, Alignment, Color, Command, Element, Event, Length, Settings, Size, Subscription, Theme};
+use std::collections::HashMap;
+
+// Every window of a multi_window::Application shares the one state, and view() is asked for each window by its Id.
+//
+// None of the windows close on their own. CloseRequested comes in as a Message instead, so the state can follow.
+pub fn main() -> iced::Result {
+  MultiWindow::run(Settings {
+    window: window::Settings {
+      size: Size::new(600.0, 400.0),
+      exit_on_close_request: false,
+      ..window::Settings::default()
+    },
+    ..Settings::default()
+  })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accent {
+  Red,
+  Green,
+  Blue,
+}
+
+impl Accent {
+  const ALL: [Accent; 3] = [Accent::Red, Accent::Green, Accent::Blue];
+
+  fn color(self) -> Color {
+    match self {
+      Accent::Red => Color::from_rgb(0.8, 0.2, 0.2),
+      Accent::Green => Color::from_rgb(0.2, 0.6, 0.3),
+      Accent::Blue => Color::from_rgb(0.2, 0.3, 0.8),
+    }
+  }
+}
+
+impl std::fmt::Display for Accent {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let name = match self {
+      Accent::Red => "Red",
+      Accent::Green => "Green",
+      Accent::Blue => "Blue",
+    };
+
+    write!(f, "{}", name)
+  }
+}
+
+/// What a window other than the main one shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+  /// A small tool palette. Any number of them can be open.
+  Palette,
+  /// The notes panel, detached from the main window. There is at most one.
+  Panel,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  OpenPalette,
+  DetachPanel,
+  AttachPanel,
+  AccentPicked(Accent),
+  NotesChanged(String),
+  CloseRequested(window::Id),
+  Closed(window::Id),
+}
+
+struct MultiWindow {
+  accent: Accent,
+  notes: String,
+  /// Every open window besides the main one, and what it shows.
+  windows: HashMap<window::Id, Kind>,
+}
+
+impl MultiWindow {
+  fn panel_window(&self) -> Option<window::Id> {
+    self
+      .windows
+      .iter()
+      .find(|(_, kind)| **kind == Kind::Panel)
+      .map(|(id, _)| *id)
+  }
+
+  fn palette(&self) -> Element<Message> {
+    let accents = Accent::ALL.iter().map(|accent| {
+      let style = if *accent == self.accent {
+        theme::Button::Primary
+      } else {
+        theme::Button::Secondary
+      };
+
+      Element::from(
+        Button::new(Text::new(accent.to_string()))
+          .on_press(Message::AccentPicked(*accent))
+          .style(style)
+          .width(Length::Fill),
+      )
+    });
+
+    Container::new(Column::with_children(accents).spacing(10))
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  /// The same panel is shown in the main window or in its own one, only the button differs.
+  fn panel(&self, is_detached: bool) -> Element<Message> {
+    let button = if is_detached {
+      Button::new("Attach").on_press(Message::AttachPanel)
+    } else {
+      Button::new("Detach").on_press(Message::DetachPanel)
+    };
+
+    Column::with_children(vec![
+      Row::with_children(vec![
+        Text::new("Notes").size(24).width(Length::Fill).into(),
+        button.into(),
+      ])
+      .align_items(Alignment::Center)
+      .into(),
+      TextInput::new("Write something", &self.notes)
+        .on_input(Message::NotesChanged)
+        .into(),
+    ])
+    .spacing(10)
+    .into()
+  }
+
+  fn main_window(&self) -> Element<Message> {
+    let panel: Element<Message> = if self.panel_window().is_some() {
+      Text::new("The notes panel is in its own window. Close it or press Attach to bring it back.").into()
+    } else {
+      self.panel(false)
+    };
+
+    Container::new(
+      Column::with_children(vec![
+        Text::new(format!("Accent: {}", self.accent))
+          .size(32)
+          .style(self.accent.color())
+          .into(),
+        Text::new(format!("Notes: {} characters", self.notes.chars().count())).into(),
+        Button::new("Open a tool palette")
+          .on_press(Message::OpenPalette)
+          .into(),
+        Rule::horizontal(10).into(),
+        panel,
+      ])
+      .spacing(20),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(20)
+    .into()
+  }
+}
+
+impl Application for MultiWindow {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      MultiWindow {
+        accent: Accent::Blue,
+        notes: String::new(),
+        windows: HashMap::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self, window: window::Id) -> String {
+    match self.windows.get(&window) {
+      Some(Kind::Palette) => String::from("Palette"),
+      Some(Kind::Panel) => String::from("Notes"),
+      None => String::from("Multi Window"),
+    }
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::OpenPalette => {
+        // spawn() hands out the Id right away, the window itself opens once the Command runs.
+        let (id, spawn) = window::spawn(window::Settings {
+          size: Size::new(200.0, 180.0),
+          resizable: false,
+          level: window::Level::AlwaysOnTop,
+          exit_on_close_request: false,
+          ..window::Settings::default()
+        });
+
+        self.windows.insert(id, Kind::Palette);
+        return spawn;
+      }
+      Message::DetachPanel => {
+        if self.panel_window().is_none() {
+          let (id, spawn) = window::spawn(window::Settings {
+            size: Size::new(400.0, 150.0),
+            exit_on_close_request: false,
+            ..window::Settings::default()
+          });
+
+          self.windows.insert(id, Kind::Panel);
+          return spawn;
+        }
+      }
+      Message::AttachPanel => {
+        if let Some(id) = self.panel_window() {
+          self.windows.remove(&id);
+          return window::close(id);
+        }
+      }
+      Message::AccentPicked(accent) => self.accent = accent,
+      Message::NotesChanged(notes) => self.notes = notes,
+      // Closing the main window closes the others too. The Application exits once none are left.
+      Message::CloseRequested(id) if id == window::Id::MAIN => {
+        let others = self.windows.drain().map(|(id, _)| window::close(id));
+
+        return Command::batch(others.chain([window::close(window::Id::MAIN)]));
+      }
+      // Closing the panel's window attaches it again, since it's no longer in windows.
+      Message::CloseRequested(id) => {
+        self.windows.remove(&id);
+        return window::close(id);
+      }
+      Message::Closed(id) => {
+        self.windows.remove(&id);
+      }
+    }
+
+    Command::none()
+  }
+
+  fn view(&self, window: window::Id) -> Element<Message> {
+    match self.windows.get(&window) {
+      Some(Kind::Palette) => self.palette(),
+      Some(Kind::Panel) => Container::new(self.panel(true)).padding(20).into(),
+      None => self.main_window(),
+    }
+  }
+
+  fn theme(&self, _window: window::Id) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    event::listen_with(|event, _status| match event {
+      Event::Window(id, window::Event::CloseRequested) => Some(Message::CloseRequested(id)),
+      Event::Window(id, window::Event::Closed) => Some(Message::Closed(id)),
+      _ => None,
+    })
+  }
+}
@@ -0,0 +1,54 @@
+use crate::{Action, Item, Route};
+use iced::widget::{Button, Column, Text, Toggler};
+use iced::{Element, Length};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  ToggleDescription(bool),
+  Edit,
+}
+
+pub struct State {
+  id: usize,
+  show_description: bool,
+}
+
+impl State {
+  pub fn new(id: usize) -> Self {
+    State {
+      id,
+      show_description: false,
+    }
+  }
+
+  pub fn update(&mut self, message: Message) -> Option<Action> {
+    match message {
+      Message::ToggleDescription(show_description) => {
+        self.show_description = show_description;
+        None
+      }
+      Message::Edit => Some(Action::Navigate(Route::Edit(self.id))),
+    }
+  }
+
+  pub fn view<'a>(&'a self, item: &'a Item) -> Element<'a, Message> {
+    let mut children = vec![
+      Text::new(item.name.as_str()).size(32).into(),
+      Toggler::new(
+        String::from("Show description"),
+        self.show_description,
+        Message::ToggleDescription,
+      )
+      .width(Length::Shrink)
+      .into(),
+    ];
+
+    if self.show_description {
+      children.push(Text::new(item.description.as_str()).into());
+    }
+
+    children.push(Button::new("Edit").on_press(Message::Edit).into());
+
+    Column::with_children(children).spacing(20).into()
+  }
+}
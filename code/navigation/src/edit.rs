@@ -0,0 +1,75 @@
+use crate::{Action, Item};
+use iced::widget::{Button, Column, Row, TextInput};
+use iced::Element;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  NameChanged(String),
+  DescriptionChanged(String),
+  Save,
+  Cancel,
+}
+
+/// A draft of the item. Nothing is written back until the user saves.
+pub struct State {
+  id: usize,
+  name: String,
+  description: String,
+}
+
+impl State {
+  pub fn new(id: usize, item: &Item) -> Self {
+    State {
+      id,
+      name: item.name.clone(),
+      description: item.description.clone(),
+    }
+  }
+
+  pub fn update(&mut self, message: Message) -> Option<Action> {
+    match message {
+      Message::NameChanged(name) => {
+        self.name = name;
+        None
+      }
+      Message::DescriptionChanged(description) => {
+        self.description = description;
+        None
+      }
+      // Submitting the description can also save, so the empty name is checked here and not only by the button.
+      Message::Save if self.name.is_empty() => None,
+      Message::Save => Some(Action::Save(
+        self.id,
+        Item {
+          name: self.name.clone(),
+          description: self.description.clone(),
+        },
+      )),
+      Message::Cancel => Some(Action::Back),
+    }
+  }
+
+  pub fn view(&self) -> Element<Message> {
+    Column::with_children(vec![
+      TextInput::new("Name", &self.name)
+        .on_input(Message::NameChanged)
+        .padding(10)
+        .into(),
+      TextInput::new("Description", &self.description)
+        .on_input(Message::DescriptionChanged)
+        .on_submit(Message::Save)
+        .padding(10)
+        .into(),
+      Row::with_children(vec![
+        Button::new("Save")
+          .on_press_maybe((!self.name.is_empty()).then_some(Message::Save))
+          .into(),
+        Button::new("Cancel").on_press(Message::Cancel).into(),
+      ])
+      .spacing(10)
+      .into(),
+    ])
+    .spacing(20)
+    .into()
+  }
+}
@@ -0,0 +1,57 @@
+use crate::{Action, Item, Route};
+use iced::widget::{Button, Column, Scrollable, Text, TextInput};
+use iced::{Element, Length};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  FilterChanged(String),
+  Open(usize),
+}
+
+pub struct State {
+  filter: String,
+}
+
+impl State {
+  pub fn new() -> Self {
+    State {
+      filter: String::new(),
+    }
+  }
+
+  pub fn update(&mut self, message: Message) -> Option<Action> {
+    match message {
+      Message::FilterChanged(filter) => {
+        self.filter = filter;
+        None
+      }
+      Message::Open(id) => Some(Action::Navigate(Route::Detail(id))),
+    }
+  }
+
+  pub fn view<'a>(&'a self, items: &'a [Item]) -> Element<'a, Message> {
+    let filter = self.filter.to_lowercase();
+
+    let items = items
+      .iter()
+      .enumerate()
+      .filter(|(_, item)| item.name.to_lowercase().contains(&filter))
+      .map(|(id, item)| {
+        Button::new(Text::new(item.name.as_str()))
+          .on_press(Message::Open(id))
+          .width(Length::Fill)
+          .into()
+      })
+      .collect();
+
+    Column::with_children(vec![
+      TextInput::new("Filter...", &self.filter)
+        .on_input(Message::FilterChanged)
+        .padding(10)
+        .into(),
+      Scrollable::new(Column::with_children(items).spacing(5)).into(),
+    ])
+    .spacing(20)
+    .into()
+  }
+}
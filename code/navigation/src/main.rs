@@ -0,0 +1,221 @@
+mod detail;
+mod edit;
+mod list;
+mod router;
+
+use iced::keyboard::KeyCode;
+use iced::widget::{Button, Column, Container, Row, Rule, Text};
+use iced::{event, executor, keyboard, subscription, Alignment, Application, Command, Element, Event, Length, Settings, Subscription, Theme};
+use router::{Retain, Router};
+use std::fmt::{Display, Formatter};
+
+pub fn main() -> iced::Result {
+  Navigation::run(Settings::default())
+}
+
+/// Every screen the application can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+  List,
+  Detail(usize),
+  Edit(usize),
+}
+
+impl Display for Route {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Route::List => write!(f, "Items"),
+      Route::Detail(id) => write!(f, "Item #{}", id + 1),
+      Route::Edit(_) => write!(f, "Edit"),
+    }
+  }
+}
+
+/// Screens don't navigate themselves. They ask the Application to do it.
+#[derive(Debug, Clone)]
+pub enum Action {
+  Navigate(Route),
+  Back,
+  Save(usize, Item),
+}
+
+#[derive(Debug, Clone)]
+pub struct Item {
+  name: String,
+  description: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  List(list::Message),
+  Detail(detail::Message),
+  Edit(edit::Message),
+  Back,
+}
+
+/// The state of a single screen.
+enum Screen {
+  List(list::State),
+  Detail(detail::State),
+  Edit(edit::State),
+}
+
+impl Screen {
+  fn new(route: &Route, items: &[Item]) -> Self {
+    match *route {
+      Route::List => Screen::List(list::State::new()),
+      Route::Detail(id) => Screen::Detail(detail::State::new(id)),
+      Route::Edit(id) => Screen::Edit(edit::State::new(id, &items[id])),
+    }
+  }
+}
+
+/// The list keeps its filter while the user browses. The detail screen starts over every time it is uncovered.
+fn retain(route: &Route) -> Retain {
+  match route {
+    Route::List => Retain::Keep,
+    Route::Detail(_) => Retain::Drop,
+    Route::Edit(_) => Retain::Drop,
+  }
+}
+
+struct Navigation {
+  items: Vec<Item>,
+  router: Router<Route, Screen>,
+}
+
+impl Navigation {
+  fn perform(&mut self, action: Action) {
+    match action {
+      Action::Navigate(route) => {
+        let screen = Screen::new(&route, &self.items);
+        self.router.push(route, screen);
+      }
+      Action::Back => self.back(),
+      Action::Save(id, item) => {
+        self.items[id] = item;
+        self.back();
+      }
+    }
+  }
+
+  fn back(&mut self) {
+    if self.router.back() {
+      let items = &self.items;
+      self.router.restore(|route| Screen::new(route, items));
+    }
+  }
+}
+
+impl Application for Navigation {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    let items = (1..=20)
+      .map(|number| Item {
+        name: format!("Item {}", number),
+        description: format!("The description of item {}.", number),
+      })
+      .collect();
+
+    (
+      Navigation {
+        items,
+        router: Router::new(Route::List, Screen::List(list::State::new()), retain),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    format!("Navigation - {}", self.router.route())
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    let action = match (message, self.router.screen_mut()) {
+      (Message::List(message), Some(Screen::List(state))) => state.update(message),
+      (Message::Detail(message), Some(Screen::Detail(state))) => state.update(message),
+      (Message::Edit(message), Some(Screen::Edit(state))) => state.update(message),
+      (Message::Back, _) => Some(Action::Back),
+      // A Message from a screen that is no longer visible.
+      _ => None,
+    };
+
+    if let Some(action) = action {
+      self.perform(action);
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let breadcrumbs = self
+      .router
+      .routes()
+      .map(|route| route.to_string())
+      .collect::<Vec<_>>()
+      .join(" / ");
+
+    let header = Row::with_children(vec![
+      Button::new("Back")
+        .on_press_maybe(self.router.can_go_back().then_some(Message::Back))
+        .into(),
+      Text::new(breadcrumbs).size(24).into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center);
+
+    let screen = match self.router.screen() {
+      Some(Screen::List(state)) => state.view(&self.items).map(Message::List),
+      Some(Screen::Detail(state)) => {
+        if let Route::Detail(id) = self.router.route() {
+          state.view(&self.items[*id]).map(Message::Detail)
+        } else {
+          Text::new("").into()
+        }
+      }
+      Some(Screen::Edit(state)) => state.view().map(Message::Edit),
+      None => Text::new("").into(),
+    };
+
+    let column = Column::with_children(vec![header.into(), Rule::horizontal(10).into(), screen]).spacing(20);
+
+    Container::new(column)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    subscription::events_with(back_shortcut)
+  }
+}
+
+/// Escape or Alt+Left goes back.
+///
+/// Events a widget already handled are skipped, so pressing Escape inside of a TextInput only unfocuses it.
+fn back_shortcut(event: Event, status: event::Status) -> Option<Message> {
+  if status == event::Status::Captured {
+    return None;
+  }
+
+  match event {
+    Event::Keyboard(keyboard::Event::KeyPressed {
+      key_code: KeyCode::Escape,
+      ..
+    }) => Some(Message::Back),
+    Event::Keyboard(keyboard::Event::KeyPressed {
+      key_code: KeyCode::Left,
+      modifiers,
+    }) if modifiers.alt() => Some(Message::Back),
+    _ => None,
+  }
+}
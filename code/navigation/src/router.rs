@@ -0,0 +1,102 @@
+/// What happens to a screen's state when another screen is pushed on top of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retain {
+  /// The screen comes back exactly as it was left.
+  Keep,
+  /// The screen is rebuilt from its route when it becomes visible again.
+  Drop,
+}
+
+struct Entry<R, S> {
+  route: R,
+  screen: Option<S>,
+}
+
+/// A back stack of routes and the state of the screens they point to.
+///
+/// The first route is the root. It can never be popped.
+pub struct Router<R, S> {
+  stack: Vec<Entry<R, S>>,
+  policy: fn(&R) -> Retain,
+}
+
+impl<R, S> Router<R, S> {
+  pub fn new(route: R, screen: S, policy: fn(&R) -> Retain) -> Self {
+    Router {
+      stack: vec![Entry {
+        route,
+        screen: Some(screen),
+      }],
+      policy,
+    }
+  }
+
+  pub fn route(&self) -> &R {
+    &self.current().route
+  }
+
+  /// The state of the visible screen.
+  ///
+  /// This is only None if the screen was dropped and `restore` has not been called yet.
+  pub fn screen(&self) -> Option<&S> {
+    self.current().screen.as_ref()
+  }
+
+  pub fn screen_mut(&mut self) -> Option<&mut S> {
+    self
+      .stack
+      .last_mut()
+      .and_then(|entry| entry.screen.as_mut())
+  }
+
+  /// Every route from the root to the visible screen.
+  pub fn routes(&self) -> impl Iterator<Item = &R> {
+    self.stack.iter().map(|entry| &entry.route)
+  }
+
+  pub fn can_go_back(&self) -> bool {
+    self.stack.len() > 1
+  }
+
+  /// Shows a new screen on top of the current one.
+  pub fn push(&mut self, route: R, screen: S) {
+    let policy = self.policy;
+
+    if let Some(covered) = self.stack.last_mut() {
+      if policy(&covered.route) == Retain::Drop {
+        covered.screen = None;
+      }
+    }
+
+    self.stack.push(Entry {
+      route,
+      screen: Some(screen),
+    });
+  }
+
+  /// Removes the current screen. Returns false when already at the root.
+  ///
+  /// Call `restore` afterwards in case the uncovered screen was dropped.
+  pub fn back(&mut self) -> bool {
+    if !self.can_go_back() {
+      return false;
+    }
+
+    self.stack.pop();
+    true
+  }
+
+  /// Rebuilds the visible screen from its route if its state was dropped.
+  pub fn restore(&mut self, f: impl FnOnce(&R) -> S) {
+    if let Some(entry) = self.stack.last_mut() {
+      if entry.screen.is_none() {
+        entry.screen = Some(f(&entry.route));
+      }
+    }
+  }
+
+  fn current(&self) -> &Entry<R, S> {
+    // The root is never popped so the stack is never empty.
+    self.stack.last().expect("the root route is never popped")
+  }
+}
@@ -0,0 +1,214 @@
+mod modal;
+mod toast;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{text_input, Button, Column, Container, Row, Text, TextInput};
+use iced::{executor, Alignment, Application, Command, Element, Length, Settings, Subscription, Theme};
+use modal::Modal;
+use serde::{Deserialize, Serialize};
+use toast::Kind;
+
+/// Unlike in basic-state-3, the error keeps what went wrong so there is something to show.
+#[derive(Debug, Clone)]
+pub enum Error {
+  APIError(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RandomQuoteApiResponse {
+  content: String,
+}
+
+pub fn main() -> iced::Result {
+  Notifications::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  GetRandomQuote,
+  GetRandomQuoteDone(Result<RandomQuoteApiResponse, Error>),
+  ShowTip,
+  OpenRename,
+  NameChanged(String),
+  ConfirmRename,
+  OpenClear,
+  ConfirmClear,
+  CloseDialog,
+  Toast(toast::Message),
+}
+
+/// The dialog that is open. Only one can be open at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialog {
+  Rename,
+  Clear,
+}
+
+struct Notifications {
+  quote: String,
+  name: String,
+  /// The name being typed into the Rename dialog. It only replaces name when the dialog is confirmed.
+  draft_name: String,
+  dialog: Option<Dialog>,
+  toasts: toast::State,
+}
+
+fn name_input_id() -> text_input::Id {
+  text_input::Id::new("name")
+}
+
+impl Application for Notifications {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      Notifications {
+        quote: "???".to_string(),
+        name: String::from("World"),
+        draft_name: String::new(),
+        dialog: None,
+        toasts: toast::State::default(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Notifications")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::GetRandomQuote => return Command::perform(get_random_quote(), Message::GetRandomQuoteDone),
+      Message::GetRandomQuoteDone(Ok(random_quote_api_response)) => {
+        self.quote = random_quote_api_response.content;
+        self
+          .toasts
+          .push(Kind::Success, "New quote", "The quote was updated.");
+      }
+      // The error that basic-state-3 throws away.
+      Message::GetRandomQuoteDone(Err(Error::APIError(error))) => {
+        self.toasts.push(Kind::Error, "Couldn't get a quote", error);
+      }
+      Message::ShowTip => {
+        self.toasts.push(
+          Kind::Info,
+          "Tip",
+          "Hover a toast to keep it from disappearing.",
+        );
+      }
+      Message::OpenRename => {
+        self.draft_name = self.name.clone();
+        self.dialog = Some(Dialog::Rename);
+        return text_input::focus(name_input_id());
+      }
+      Message::NameChanged(name) => self.draft_name = name,
+      Message::ConfirmRename => {
+        if !self.draft_name.is_empty() {
+          self.name = self.draft_name.clone();
+          self.dialog = None;
+        }
+      }
+      Message::OpenClear => self.dialog = Some(Dialog::Clear),
+      Message::ConfirmClear => {
+        self.quote = "???".to_string();
+        self.dialog = None;
+        self
+          .toasts
+          .push(Kind::Info, "Cleared", "The quote was cleared.");
+      }
+      Message::CloseDialog => self.dialog = None,
+      Message::Toast(message) => self.toasts.update(message),
+    }
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let content = Column::with_children(vec![
+      Text::new(format!("Hello {}!", self.name)).size(32).into(),
+      Text::new(self.quote.as_str()).into(),
+      Row::with_children(vec![
+        Button::new("Random Quote")
+          .on_press(Message::GetRandomQuote)
+          .into(),
+        Button::new("Rename").on_press(Message::OpenRename).into(),
+        Button::new("Clear").on_press(Message::OpenClear).into(),
+        Button::new("Show a tip").on_press(Message::ShowTip).into(),
+      ])
+      .spacing(10)
+      .into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center);
+
+    let base = Container::new(content)
+      .align_x(Horizontal::Center)
+      .align_y(Vertical::Center)
+      .width(Length::Fill)
+      .height(Length::Fill);
+
+    let base: Element<Message> = match self.dialog {
+      Some(Dialog::Rename) => {
+        let input = TextInput::new("Name", &self.draft_name)
+          .id(name_input_id())
+          .on_input(Message::NameChanged)
+          .on_submit(Message::ConfirmRename);
+
+        // An empty name can't be confirmed.
+        let on_confirm = (!self.draft_name.is_empty()).then_some(Message::ConfirmRename);
+
+        Modal::new(
+          base,
+          modal::dialog("Rename", input, "Rename", on_confirm, Message::CloseDialog),
+          Message::CloseDialog,
+        )
+        .into()
+      }
+      Some(Dialog::Clear) => {
+        let body = Text::new("The current quote will be lost.");
+
+        Modal::new(
+          base,
+          modal::dialog(
+            "Clear the quote?",
+            body,
+            "Clear",
+            Some(Message::ConfirmClear),
+            Message::CloseDialog,
+          ),
+          Message::CloseDialog,
+        )
+        .into()
+      }
+      None => base.into(),
+    };
+
+    self.toasts.view(base, Message::Toast)
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    self.toasts.subscription().map(Message::Toast)
+  }
+}
+
+async fn get_random_quote() -> Result<RandomQuoteApiResponse, Error> {
+  Ok(
+    reqwest::get("https://api.quotable.io/random")
+      .await?
+      .json::<RandomQuoteApiResponse>()
+      .await?,
+  )
+}
+
+impl From<reqwest::Error> for Error {
+  fn from(error: reqwest::Error) -> Error {
+    Error::APIError(error.to_string())
+  }
+}
@@ -0,0 +1,329 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::operation::{self, focusable, Operation};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::Widget;
+use iced::advanced::{overlay, renderer, Clipboard, Renderer as _, Shell};
+use iced::keyboard::{self, KeyCode};
+use iced::widget::{Button, Column, Container, Row, Space, Text};
+use iced::{event, mouse, theme, Alignment, Color, Element, Event, Length, Point, Rectangle, Renderer, Size, Theme};
+
+/// Shows some content on top of the base, behind a backdrop that blocks the base.
+///
+/// The modal is open for as long as view() wraps the base in one. Clicking the backdrop or pressing Escape produces
+/// the on_close Message, the Application decides whether that really closes it.
+pub struct Modal<'a, Message> {
+  base: Element<'a, Message>,
+  content: Element<'a, Message>,
+  on_close: Message,
+}
+
+impl<'a, Message> Modal<'a, Message> {
+  pub fn new(base: impl Into<Element<'a, Message>>, content: impl Into<Element<'a, Message>>, on_close: Message) -> Self {
+    Modal {
+      base: base.into(),
+      content: content.into(),
+      on_close,
+    }
+  }
+}
+
+/// The base and the content are the two children, each with its own Tree.
+impl<'a, Message> Widget<Message, Renderer> for Modal<'a, Message>
+where
+  Message: Clone + Send + 'static,
+{
+  fn children(&self) -> Vec<Tree> {
+    vec![Tree::new(&self.base), Tree::new(&self.content)]
+  }
+
+  fn diff(&self, tree: &mut Tree) {
+    tree.diff_children(&[&self.base, &self.content]);
+  }
+
+  fn tag(&self) -> tree::Tag {
+    tree::Tag::stateless()
+  }
+
+  fn width(&self) -> Length {
+    self.base.as_widget().width()
+  }
+
+  fn height(&self) -> Length {
+    self.base.as_widget().height()
+  }
+
+  fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    self.base.as_widget().layout(renderer, limits)
+  }
+
+  fn operate(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    self
+      .base
+      .as_widget()
+      .operate(&mut tree.children[0], layout, renderer, operation);
+  }
+
+  /// The base is drawn as usual, but while the modal is open it gets no events.
+  fn on_event(
+    &mut self,
+    _tree: &mut Tree,
+    _event: Event,
+    _layout: Layout<'_>,
+    _cursor: mouse::Cursor,
+    _renderer: &Renderer,
+    _clipboard: &mut dyn Clipboard,
+    _shell: &mut Shell<'_, Message>,
+    _viewport: &Rectangle,
+  ) -> event::Status {
+    event::Status::Ignored
+  }
+
+  fn draw(
+    &self,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+  ) {
+    self.base.as_widget().draw(
+      &tree.children[0],
+      renderer,
+      theme,
+      style,
+      layout,
+      cursor,
+      viewport,
+    );
+  }
+
+  fn overlay<'b>(&'b mut self, tree: &'b mut Tree, layout: Layout<'_>, _renderer: &Renderer) -> Option<overlay::Element<'b, Message, Renderer>> {
+    Some(overlay::Element::new(
+      layout.position(),
+      Box::new(ModalOverlay {
+        content: &mut self.content,
+        tree: &mut tree.children[1],
+        size: layout.bounds().size(),
+        on_close: self.on_close.clone(),
+      }),
+    ))
+  }
+}
+
+struct ModalOverlay<'a, 'b, Message> {
+  content: &'b mut Element<'a, Message>,
+  tree: &'b mut Tree,
+  /// The size of the base, which the backdrop covers.
+  size: Size,
+  on_close: Message,
+}
+
+impl<'a, 'b, Message> ModalOverlay<'a, 'b, Message>
+where
+  Message: Send + 'static,
+{
+  /// Runs a focus operation on the content only, so Tab can't move the focus to the base.
+  ///
+  /// Some operations, like focus_next, first count the focusable widgets and then chain into a second pass.
+  fn focus(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: impl Operation<Message> + 'static) {
+    let mut operation: Box<dyn Operation<Message>> = Box::new(operation);
+
+    loop {
+      self
+        .content
+        .as_widget()
+        .operate(self.tree, layout, renderer, operation.as_mut());
+
+      match operation.finish() {
+        operation::Outcome::Chain(next) => operation = next,
+        _ => break,
+      }
+    }
+  }
+}
+
+impl<'a, 'b, Message> overlay::Overlay<Message, Renderer> for ModalOverlay<'a, 'b, Message>
+where
+  Message: Clone + Send + 'static,
+{
+  fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> layout::Node {
+    let limits = layout::Limits::new(Size::ZERO, self.size);
+
+    let mut content = self.content.as_widget().layout(renderer, &limits);
+    content.align(Alignment::Center, Alignment::Center, self.size);
+
+    let mut node = layout::Node::with_children(self.size, vec![content]);
+    node.move_to(position);
+    node
+  }
+
+  fn on_event(
+    &mut self,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+  ) -> event::Status {
+    let content_layout = layout
+      .children()
+      .next()
+      .expect("The overlay has the content as its only child");
+
+    match &event {
+      Event::Mouse(mouse::Event::ButtonPressed(_)) if !cursor.is_over(content_layout.bounds()) => {
+        shell.publish(self.on_close.clone());
+        return event::Status::Captured;
+      }
+      Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code: KeyCode::Escape,
+        ..
+      }) => {
+        shell.publish(self.on_close.clone());
+        return event::Status::Captured;
+      }
+      Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code: KeyCode::Tab,
+        modifiers,
+      }) => {
+        if modifiers.shift() {
+          self.focus(content_layout, renderer, focusable::focus_previous());
+        } else {
+          self.focus(content_layout, renderer, focusable::focus_next());
+        }
+        return event::Status::Captured;
+      }
+      _ => {}
+    }
+
+    let status = self.content.as_widget_mut().on_event(
+      self.tree,
+      event.clone(),
+      content_layout,
+      cursor,
+      renderer,
+      clipboard,
+      shell,
+      &layout.bounds(),
+    );
+
+    // Keyboard events the content didn't want are captured anyway, they must not reach the base.
+    match event {
+      Event::Keyboard(_) => event::Status::Captured,
+      _ => status,
+    }
+  }
+
+  fn draw(&self, renderer: &mut Renderer, theme: &Theme, style: &renderer::Style, layout: Layout<'_>, cursor: mouse::Cursor) {
+    renderer.fill_quad(
+      renderer::Quad {
+        bounds: layout.bounds(),
+        border_radius: 0.0.into(),
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+      },
+      Color {
+        a: 0.7,
+        ..Color::BLACK
+      },
+    );
+
+    self.content.as_widget().draw(
+      self.tree,
+      renderer,
+      theme,
+      style,
+      layout
+        .children()
+        .next()
+        .expect("The overlay has the content as its only child"),
+      cursor,
+      &layout.bounds(),
+    );
+  }
+
+  fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    self.content.as_widget().operate(
+      self.tree,
+      layout
+        .children()
+        .next()
+        .expect("The overlay has the content as its only child"),
+      renderer,
+      operation,
+    );
+  }
+
+  fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, viewport: &Rectangle, renderer: &Renderer) -> mouse::Interaction {
+    self.content.as_widget().mouse_interaction(
+      self.tree,
+      layout
+        .children()
+        .next()
+        .expect("The overlay has the content as its only child"),
+      cursor,
+      viewport,
+      renderer,
+    )
+  }
+
+  fn overlay<'c>(&'c mut self, layout: Layout<'_>, renderer: &Renderer) -> Option<overlay::Element<'c, Message, Renderer>> {
+    self.content.as_widget_mut().overlay(
+      self.tree,
+      layout
+        .children()
+        .next()
+        .expect("The overlay has the content as its only child"),
+      renderer,
+    )
+  }
+}
+
+impl<'a, Message> From<Modal<'a, Message>> for Element<'a, Message>
+where
+  Message: Clone + Send + 'static,
+{
+  fn from(modal: Modal<'a, Message>) -> Self {
+    Element::new(modal)
+  }
+}
+
+/// A dialog box with a title, some content and Cancel and Confirm buttons. Meant to be the content of a Modal.
+pub fn dialog<'a, Message>(
+  title: &str,
+  content: impl Into<Element<'a, Message>>,
+  confirm: &str,
+  on_confirm: Option<Message>,
+  on_cancel: Message,
+) -> Element<'a, Message>
+where
+  Message: Clone + 'a,
+{
+  let buttons = Row::with_children(vec![
+    Space::with_width(Length::Fill).into(),
+    Button::new("Cancel")
+      .on_press(on_cancel)
+      .style(theme::Button::Secondary)
+      .into(),
+    Button::new(Text::new(confirm.to_string()))
+      .on_press_maybe(on_confirm)
+      .into(),
+  ])
+  .spacing(10);
+
+  Container::new(
+    Column::with_children(vec![
+      Text::new(title.to_string()).size(24).into(),
+      content.into(),
+      buttons.into(),
+    ])
+    .spacing(20),
+  )
+  .width(400)
+  .padding(20)
+  .style(theme::Container::Box)
+  .into()
+}
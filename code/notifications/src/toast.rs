@@ -0,0 +1,472 @@
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::widget::tree::{self, Tree};
+use iced::advanced::widget::{Operation, Widget};
+use iced::advanced::{overlay, renderer, Clipboard, Shell};
+use iced::widget::{container, Button, Column, Container, Row, Space, Text};
+use iced::{event, mouse, theme, Alignment, Color, Element, Event, Length, Point, Rectangle, Renderer, Size, Subscription, Theme};
+use std::time::{Duration, Instant};
+
+/// How long a toast stays when nobody hovers it.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+  Info,
+  Success,
+  Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+  id: usize,
+  kind: Kind,
+  title: String,
+  body: String,
+  remaining: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Tick(Instant),
+  Dismiss(usize),
+  Hovered(Option<usize>),
+}
+
+/// The toasts that are showing, newest last.
+#[derive(Debug, Default)]
+pub struct State {
+  toasts: Vec<Toast>,
+  next_id: usize,
+  /// The hovered toast doesn't count down, so it can't disappear while someone is reading it.
+  hovered: Option<usize>,
+  last_tick: Option<Instant>,
+}
+
+impl State {
+  pub fn push(&mut self, kind: Kind, title: impl Into<String>, body: impl Into<String>) {
+    self.toasts.push(Toast {
+      id: self.next_id,
+      kind,
+      title: title.into(),
+      body: body.into(),
+      remaining: TIMEOUT,
+    });
+    self.next_id += 1;
+  }
+
+  pub fn update(&mut self, message: Message) {
+    match message {
+      Message::Tick(now) => {
+        // Ticks only arrive while there are toasts, so the time since the last one can be much longer.
+        let elapsed = self
+          .last_tick
+          .map_or(Duration::ZERO, |last_tick| now - last_tick);
+        self.last_tick = Some(now);
+
+        for toast in &mut self.toasts {
+          if Some(toast.id) != self.hovered {
+            toast.remaining = toast.remaining.saturating_sub(elapsed);
+          }
+        }
+
+        self.toasts.retain(|toast| !toast.remaining.is_zero());
+      }
+      Message::Dismiss(id) => {
+        self.toasts.retain(|toast| toast.id != id);
+
+        if self.hovered == Some(id) {
+          self.hovered = None;
+        }
+      }
+      Message::Hovered(id) => self.hovered = id,
+    }
+
+    if self.toasts.is_empty() {
+      self.last_tick = None;
+    }
+  }
+
+  /// Counts down while there is something to count down.
+  pub fn subscription(&self) -> Subscription<Message> {
+    if self.toasts.is_empty() {
+      Subscription::none()
+    } else {
+      iced::time::every(Duration::from_millis(100)).map(Message::Tick)
+    }
+  }
+
+  /// Shows the toasts in the bottom right corner, on top of the base.
+  ///
+  /// The base is always wrapped, even without toasts, so its widgets keep their state when the first one shows up.
+  pub fn view<'a, AppMessage>(&'a self, base: impl Into<Element<'a, AppMessage>>, map: fn(Message) -> AppMessage) -> Element<'a, AppMessage>
+  where
+    AppMessage: 'a,
+  {
+    let toasts = Column::with_children(
+      self
+        .toasts
+        .iter()
+        .map(|toast| view_toast(toast).map(map))
+        .collect(),
+    )
+    .spacing(10)
+    .width(300)
+    .padding(20);
+
+    Element::new(Manager {
+      base: base.into(),
+      toasts: toasts.into(),
+      ids: self.toasts.iter().map(|toast| toast.id).collect(),
+      on_hover: Box::new(move |id| map(Message::Hovered(id))),
+    })
+  }
+}
+
+fn view_toast<'a>(toast: &'a Toast) -> Element<'a, Message> {
+  let title = Row::with_children(vec![
+    Text::new(toast.title.as_str()).size(18).into(),
+    Space::with_width(Length::Fill).into(),
+    Button::new(Text::new("x").size(14))
+      .on_press(Message::Dismiss(toast.id))
+      .padding([2, 8])
+      .style(theme::Button::Text)
+      .into(),
+  ])
+  .align_items(Alignment::Center);
+
+  Container::new(Column::with_children(vec![title.into(), Text::new(toast.body.as_str()).into()]).spacing(5))
+    .width(Length::Fill)
+    .padding(10)
+    .style(theme::Container::Custom(Box::new(ToastStyle(toast.kind))))
+    .into()
+}
+
+/// A background in the color of the Kind.
+struct ToastStyle(Kind);
+
+impl container::StyleSheet for ToastStyle {
+  type Style = Theme;
+
+  fn appearance(&self, style: &Self::Style) -> container::Appearance {
+    let palette = style.extended_palette();
+
+    let pair = match self.0 {
+      Kind::Info => palette.primary.weak,
+      Kind::Success => palette.success.weak,
+      Kind::Error => palette.danger.weak,
+    };
+
+    container::Appearance {
+      text_color: Some(pair.text),
+      background: Some(pair.color.into()),
+      border_radius: 4.0.into(),
+      border_width: 1.0,
+      border_color: Color {
+        a: 0.3,
+        ..Color::BLACK
+      },
+    }
+  }
+}
+
+/// Draws the toasts in an overlay, so they float over the base instead of taking up room in its layout.
+struct Manager<'a, Message> {
+  base: Element<'a, Message>,
+  toasts: Element<'a, Message>,
+  /// The id of each toast, in the order they are shown.
+  ids: Vec<usize>,
+  on_hover: Box<dyn Fn(Option<usize>) -> Message + 'a>,
+}
+
+/// The id of the toast the cursor was over when the last event came in. Only changes are reported.
+#[derive(Debug, Default)]
+struct ManagerState {
+  hovered: Option<usize>,
+}
+
+impl<'a, Message> Widget<Message, Renderer> for Manager<'a, Message>
+where
+  Message: 'a,
+{
+  fn tag(&self) -> tree::Tag {
+    tree::Tag::of::<ManagerState>()
+  }
+
+  fn state(&self) -> tree::State {
+    tree::State::new(ManagerState::default())
+  }
+
+  fn children(&self) -> Vec<Tree> {
+    vec![Tree::new(&self.base), Tree::new(&self.toasts)]
+  }
+
+  fn diff(&self, tree: &mut Tree) {
+    tree.diff_children(&[&self.base, &self.toasts]);
+  }
+
+  fn width(&self) -> Length {
+    self.base.as_widget().width()
+  }
+
+  fn height(&self) -> Length {
+    self.base.as_widget().height()
+  }
+
+  fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    self.base.as_widget().layout(renderer, limits)
+  }
+
+  fn operate(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    self
+      .base
+      .as_widget()
+      .operate(&mut tree.children[0], layout, renderer, operation);
+  }
+
+  fn on_event(
+    &mut self,
+    tree: &mut Tree,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    viewport: &Rectangle,
+  ) -> event::Status {
+    self.base.as_widget_mut().on_event(
+      &mut tree.children[0],
+      event,
+      layout,
+      cursor,
+      renderer,
+      clipboard,
+      shell,
+      viewport,
+    )
+  }
+
+  fn mouse_interaction(&self, tree: &Tree, layout: Layout<'_>, cursor: mouse::Cursor, viewport: &Rectangle, renderer: &Renderer) -> mouse::Interaction {
+    self
+      .base
+      .as_widget()
+      .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+  }
+
+  fn draw(
+    &self,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+  ) {
+    self.base.as_widget().draw(
+      &tree.children[0],
+      renderer,
+      theme,
+      style,
+      layout,
+      cursor,
+      viewport,
+    );
+  }
+
+  fn overlay<'b>(&'b mut self, tree: &'b mut Tree, layout: Layout<'_>, renderer: &Renderer) -> Option<overlay::Element<'b, Message, Renderer>> {
+    let Tree {
+      state, children, ..
+    } = tree;
+    let (base_tree, toasts_tree) = children.split_at_mut(1);
+
+    // Overlays of the base, like an open Modal, are shown too, below the toasts.
+    let base = self
+      .base
+      .as_widget_mut()
+      .overlay(&mut base_tree[0], layout, renderer);
+
+    Some(overlay::Element::new(
+      layout.position(),
+      Box::new(ToastOverlay {
+        toasts: &mut self.toasts,
+        tree: &mut toasts_tree[0],
+        state: state.downcast_mut::<ManagerState>(),
+        ids: &self.ids,
+        on_hover: self.on_hover.as_ref(),
+        size: layout.bounds().size(),
+        base,
+      }),
+    ))
+  }
+}
+
+struct ToastOverlay<'a, 'b, Message> {
+  toasts: &'b mut Element<'a, Message>,
+  tree: &'b mut Tree,
+  state: &'b mut ManagerState,
+  ids: &'b [usize],
+  on_hover: &'b dyn Fn(Option<usize>) -> Message,
+  size: Size,
+  base: Option<overlay::Element<'b, Message, Renderer>>,
+}
+
+impl<'a, 'b, Message> ToastOverlay<'a, 'b, Message> {
+  /// The id of the toast under the cursor.
+  fn toast_at(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+    let toasts = layout.children().next()?;
+
+    toasts
+      .children()
+      .zip(self.ids)
+      .find(|(toast, _)| cursor.is_over(toast.bounds()))
+      .map(|(_, id)| *id)
+  }
+}
+
+impl<'a, 'b, Message> overlay::Overlay<Message, Renderer> for ToastOverlay<'a, 'b, Message> {
+  /// The toasts are the first child, the overlay of the base, if there is one, the second.
+  fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+    let limits = layout::Limits::new(Size::ZERO, self.size);
+
+    let mut toasts = self.toasts.as_widget().layout(renderer, &limits);
+    toasts.align(Alignment::End, Alignment::End, self.size);
+    toasts.move_to(Point::new(
+      position.x + toasts.bounds().x,
+      position.y + toasts.bounds().y,
+    ));
+
+    let mut children = vec![toasts];
+    children.extend(
+      self
+        .base
+        .as_ref()
+        .map(|base| base.layout(renderer, bounds, iced::Vector::ZERO)),
+    );
+
+    layout::Node::with_children(bounds, children)
+  }
+
+  fn on_event(
+    &mut self,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+  ) -> event::Status {
+    let mut children = layout.children();
+    let toasts_layout = children
+      .next()
+      .expect("The toasts are always the first child");
+    let hovered = self.toast_at(layout, cursor);
+
+    // Checked on every event, not just CursorMoved. When toasts come or go, a different one can end up under a cursor
+    // that didn't move, and the redraw that follows is enough to notice.
+    if hovered != self.state.hovered {
+      self.state.hovered = hovered;
+      shell.publish((self.on_hover)(hovered));
+    }
+
+    let status = self.toasts.as_widget_mut().on_event(
+      self.tree,
+      event.clone(),
+      toasts_layout,
+      cursor,
+      renderer,
+      clipboard,
+      shell,
+      &layout.bounds(),
+    );
+
+    // A click on a toast is for the toast only. Without this, a Modal below would take it as a click on its backdrop.
+    if hovered.is_some() && matches!(event, Event::Mouse(mouse::Event::ButtonPressed(_))) {
+      return event::Status::Captured;
+    }
+
+    match (&mut self.base, children.next()) {
+      (Some(base), Some(base_layout)) => status.merge(base.on_event(event, base_layout, cursor, renderer, clipboard, shell)),
+      _ => status,
+    }
+  }
+
+  fn draw(&self, renderer: &mut Renderer, theme: &Theme, style: &renderer::Style, layout: Layout<'_>, cursor: mouse::Cursor) {
+    let mut children = layout.children();
+    let toasts_layout = children
+      .next()
+      .expect("The toasts are always the first child");
+
+    if let (Some(base), Some(base_layout)) = (&self.base, children.next()) {
+      base.draw(renderer, theme, style, base_layout, cursor);
+    }
+
+    self.toasts.as_widget().draw(
+      self.tree,
+      renderer,
+      theme,
+      style,
+      toasts_layout,
+      cursor,
+      &layout.bounds(),
+    );
+  }
+
+  fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation<Message>) {
+    let mut children = layout.children();
+    let toasts_layout = children
+      .next()
+      .expect("The toasts are always the first child");
+
+    self
+      .toasts
+      .as_widget()
+      .operate(self.tree, toasts_layout, renderer, operation);
+
+    if let (Some(base), Some(base_layout)) = (&mut self.base, children.next()) {
+      base.operate(base_layout, renderer, operation);
+    }
+  }
+
+  fn mouse_interaction(&self, layout: Layout<'_>, cursor: mouse::Cursor, viewport: &Rectangle, renderer: &Renderer) -> mouse::Interaction {
+    let mut children = layout.children();
+    let toasts_layout = children
+      .next()
+      .expect("The toasts are always the first child");
+
+    if self.toast_at(layout, cursor).is_some() {
+      return self
+        .toasts
+        .as_widget()
+        .mouse_interaction(self.tree, toasts_layout, cursor, viewport, renderer);
+    }
+
+    match (&self.base, children.next()) {
+      (Some(base), Some(base_layout)) => base.mouse_interaction(base_layout, cursor, viewport, renderer),
+      _ => mouse::Interaction::default(),
+    }
+  }
+
+  /// Overlays nested in the overlay of the base, like a PickList opened inside a Modal, would be lost without this.
+  fn overlay<'c>(&'c mut self, layout: Layout<'_>, renderer: &Renderer) -> Option<overlay::Element<'c, Message, Renderer>> {
+    let base_layout = layout.children().nth(1)?;
+
+    self.base.as_mut()?.overlay(base_layout, renderer)
+  }
+
+  /// Only the toasts themselves and the overlay of the base are in the way of the cursor, not the whole window.
+  fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+    let mut children = layout.children();
+    let toasts_layout = children
+      .next()
+      .expect("The toasts are always the first child");
+
+    toasts_layout
+      .children()
+      .any(|toast| toast.bounds().contains(cursor_position))
+      || match (&self.base, children.next()) {
+        (Some(base), Some(base_layout)) => base.is_over(base_layout, renderer, cursor_position),
+        _ => false,
+      }
+  }
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: settings-persistence [--config PATH] [--title TITLE] [--size WIDTHxHEIGHT] [--position X,Y] [--dark | --light]";
+
+/// Command line arguments. Anything that is set overrides the config file for this run.
+#[derive(Debug, Default)]
+pub struct Args {
+  pub config_path: Option<PathBuf>,
+  pub title: Option<String>,
+  pub size: Option<(u32, u32)>,
+  pub position: Option<(i32, i32)>,
+  pub dark_mode: Option<bool>,
+}
+
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+  let mut parsed = Args::default();
+
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--config" => parsed.config_path = Some(PathBuf::from(value(&arg, args.next())?)),
+      "--title" => parsed.title = Some(value(&arg, args.next())?),
+      "--size" => parsed.size = Some(pair(&arg, &value(&arg, args.next())?, 'x')?),
+      "--position" => parsed.position = Some(pair(&arg, &value(&arg, args.next())?, ',')?),
+      "--dark" => parsed.dark_mode = Some(true),
+      "--light" => parsed.dark_mode = Some(false),
+      _ => return Err(format!("Unknown argument {}", arg)),
+    }
+  }
+
+  Ok(parsed)
+}
+
+fn value(arg: &str, value: Option<String>) -> Result<String, String> {
+  value.ok_or_else(|| format!("{} needs a value", arg))
+}
+
+/// Parses two numbers separated by `separator`, like 800x600 or 100,50.
+fn pair<T: std::str::FromStr>(arg: &str, value: &str, separator: char) -> Result<(T, T), String> {
+  let invalid = || {
+    format!(
+      "{} expects two numbers separated by '{}', got {}",
+      arg, separator, value
+    )
+  };
+
+  let (first, second) = value.split_once(separator).ok_or_else(invalid)?;
+
+  Ok((
+    first.trim().parse().map_err(|_| invalid())?,
+    second.trim().parse().map_err(|_| invalid())?,
+  ))
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The range the text size is kept in, whether it comes from the file or from the buttons.
+pub const MIN_TEXT_SIZE: f32 = 10.0;
+pub const MAX_TEXT_SIZE: f32 = 40.0;
+
+/// Everything that is remembered between runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+  pub window: WindowConfig,
+  pub dark_mode: bool,
+  pub text_size: f32,
+  pub name: String,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config {
+      window: WindowConfig::default(),
+      dark_mode: false,
+      text_size: 20.0,
+      name: String::new(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowConfig {
+  pub width: u32,
+  pub height: u32,
+  /// None lets the window be centered.
+  pub position: Option<(i32, i32)>,
+}
+
+impl Default for WindowConfig {
+  fn default() -> Self {
+    WindowConfig {
+      width: 800,
+      height: 600,
+      position: None,
+    }
+  }
+}
+
+/// Reads the config file. A missing file is not an error, it just means this is the first run.
+pub fn load(path: &Path) -> Result<Config, String> {
+  match std::fs::read_to_string(path) {
+    Ok(contents) => {
+      let mut config: Config = serde_json::from_str(&contents).map_err(|error| format!("{} is not valid: {}", path.display(), error))?;
+
+      // The file may have been edited by hand.
+      config.text_size = config.text_size.clamp(MIN_TEXT_SIZE, MAX_TEXT_SIZE);
+
+      Ok(config)
+    }
+    Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+    Err(error) => Err(format!("Can't read {}: {}", path.display(), error)),
+  }
+}
+
+/// Where back_up moves a config file that couldn't be loaded.
+pub fn backup_path(path: &Path) -> PathBuf {
+  let mut backup = path.as_os_str().to_owned();
+  backup.push(".bak");
+  PathBuf::from(backup)
+}
+
+/// Moves a config file that couldn't be loaded out of the way, so saving doesn't overwrite what may still be repaired.
+pub fn back_up(path: &Path) -> Result<PathBuf, String> {
+  let backup = backup_path(path);
+
+  std::fs::rename(path, &backup).map_err(|error| {
+    format!(
+      "Can't move {} to {}: {}",
+      path.display(),
+      backup.display(),
+      error
+    )
+  })?;
+
+  Ok(backup)
+}
+
+pub fn save(path: &Path, config: &Config) -> Result<(), String> {
+  let contents = serde_json::to_string_pretty(config).map_err(|error| error.to_string())?;
+
+  std::fs::write(path, contents).map_err(|error| format!("Can't write {}: {}", path.display(), error))
+}
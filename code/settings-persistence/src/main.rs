@@ -0,0 +1,273 @@
+mod args;
+mod config;
+
+use config::Config;
+use iced::widget::{Button, Column, Container, Row, Text, TextInput, Toggler};
+use iced::{executor, subscription, window, Alignment, Application, Color, Command, Element, Event, Length, Settings, Subscription, Theme};
+use std::path::PathBuf;
+
+pub fn main() -> iced::Result {
+  let args = match args::parse(std::env::args().skip(1)) {
+    Ok(args) => args,
+    Err(error) => {
+      eprintln!("{}\n{}", error, args::USAGE);
+      std::process::exit(2);
+    }
+  };
+
+  let config_path = args
+    .config_path
+    .unwrap_or_else(|| PathBuf::from("settings.json"));
+
+  // A broken config file shouldn't stop the app from starting. Start with the defaults and tell the user.
+  let (config, load_error) = match config::load(&config_path) {
+    Ok(config) => (config, None),
+    Err(error) => (Config::default(), Some(error)),
+  };
+
+  let overrides = Overrides {
+    size: args.size,
+    position: args.position,
+    dark_mode: args.dark_mode,
+  };
+
+  let window = window::Settings {
+    size: overrides
+      .size
+      .unwrap_or((config.window.width, config.window.height)),
+    position: match overrides.position.or(config.window.position) {
+      Some((x, y)) => window::Position::Specific(x, y),
+      None => window::Position::Centered,
+    },
+    min_size: Some((400, 300)),
+    ..window::Settings::default()
+  };
+
+  let flags = Flags {
+    config,
+    overrides,
+    config_path,
+    load_error,
+    title: args
+      .title
+      .unwrap_or_else(|| String::from("Settings Persistence")),
+  };
+
+  SettingsPersistence::run(Settings {
+    window,
+    // We want to save before closing, so Iced has to ask us first.
+    exit_on_close_request: false,
+    ..Settings::with_flags(flags)
+  })
+}
+
+/// Settings from the command line. They are for this run only and never saved.
+///
+/// Each one is dropped as soon as the user changes that setting in the app, from then on the config is used and saved.
+#[derive(Debug, Default)]
+pub struct Overrides {
+  size: Option<(u32, u32)>,
+  position: Option<(i32, i32)>,
+  dark_mode: Option<bool>,
+}
+
+/// Everything main() figured out before the Application started.
+pub struct Flags {
+  config: Config,
+  overrides: Overrides,
+  config_path: PathBuf,
+  load_error: Option<String>,
+  title: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Resized(u32, u32),
+  Moved(i32, i32),
+  CloseRequested,
+  ToggleDarkMode(bool),
+  TextSize(f32),
+  NameChanged(String),
+}
+
+struct SettingsPersistence {
+  /// What is saved when the window closes.
+  config: Config,
+  overrides: Overrides,
+  config_path: PathBuf,
+  load_error: Option<String>,
+  title: String,
+}
+
+impl SettingsPersistence {
+  fn is_dark_mode(&self) -> bool {
+    self.overrides.dark_mode.unwrap_or(self.config.dark_mode)
+  }
+}
+
+impl Application for SettingsPersistence {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = Flags;
+
+  fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      SettingsPersistence {
+        config: flags.config,
+        overrides: flags.overrides,
+        config_path: flags.config_path,
+        load_error: flags.load_error,
+        title: flags.title,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    self.title.clone()
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      // The window also reports the size and position it was opened with. Those don't count as changes by the user.
+      Message::Resized(width, height) => {
+        if self.overrides.size != Some((width, height)) {
+          self.overrides.size = None;
+          self.config.window.width = width;
+          self.config.window.height = height;
+        }
+      }
+      Message::Moved(x, y) => {
+        if self.overrides.position != Some((x, y)) {
+          self.overrides.position = None;
+          self.config.window.position = Some((x, y));
+        }
+      }
+      Message::CloseRequested => {
+        // The window is about to go away, so stderr is the only place left to report a failure.
+        // A file that couldn't be loaded is moved aside first. If that fails, nothing is saved rather than lose it.
+        let backed_up = match self.load_error {
+          Some(_) => config::back_up(&self.config_path).map(|_| ()),
+          None => Ok(()),
+        };
+
+        if let Err(error) = backed_up.and_then(|()| config::save(&self.config_path, &self.config)) {
+          eprintln!("{}", error);
+        }
+
+        return window::close();
+      }
+      Message::ToggleDarkMode(dark_mode) => {
+        self.overrides.dark_mode = None;
+        self.config.dark_mode = dark_mode;
+      }
+      Message::TextSize(text_size) => self.config.text_size = text_size.clamp(config::MIN_TEXT_SIZE, config::MAX_TEXT_SIZE),
+      Message::NameChanged(name) => self.config.name = name,
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let text_size = self.config.text_size;
+
+    let greeting = if self.config.name.is_empty() {
+      String::from("Hello! What's your name?")
+    } else {
+      format!("Hello, {}!", self.config.name)
+    };
+
+    let (width, height) = self
+      .overrides
+      .size
+      .unwrap_or((self.config.window.width, self.config.window.height));
+
+    let geometry = format!(
+      "Window: {}x{} at {}",
+      width,
+      height,
+      match self.overrides.position.or(self.config.window.position) {
+        Some((x, y)) => format!("{}, {}", x, y),
+        None => String::from("the center of the screen"),
+      }
+    );
+
+    let mut content = vec![Text::new(greeting).size(text_size + 10.0).into()];
+
+    if let Some(error) = &self.load_error {
+      content.push(
+        Text::new(format!(
+          "Using the default settings. {}. The file will be moved to {} when the window closes.",
+          error,
+          config::backup_path(&self.config_path).display()
+        ))
+        .style(Color::from_rgb(0.8, 0.2, 0.2))
+        .into(),
+      );
+    }
+
+    content.extend([
+      TextInput::new("Your name", &self.config.name)
+        .on_input(Message::NameChanged)
+        .size(text_size)
+        .padding(10)
+        .into(),
+      Toggler::new(
+        String::from("Dark mode"),
+        self.is_dark_mode(),
+        Message::ToggleDarkMode,
+      )
+      .text_size(text_size)
+      .width(Length::Shrink)
+      .into(),
+      Row::with_children(vec![
+        Button::new("A-")
+          .on_press(Message::TextSize(text_size - 2.0))
+          .into(),
+        Text::new(format!("Text size: {}", text_size))
+          .size(text_size)
+          .into(),
+        Button::new("A+")
+          .on_press(Message::TextSize(text_size + 2.0))
+          .into(),
+      ])
+      .spacing(10)
+      .align_items(Alignment::Center)
+      .into(),
+      Text::new(geometry).size(text_size).into(),
+      Text::new(format!(
+        "Everything above is saved to {} when the window closes. Settings from the command line are not, unless you change them here.",
+        self.config_path.display()
+      ))
+      .size(text_size)
+      .into(),
+    ]);
+
+    Container::new(Column::with_children(content).spacing(20).max_width(600))
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .center_x()
+      .center_y()
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    if self.is_dark_mode() {
+      Theme::Dark
+    } else {
+      Theme::Light
+    }
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    // Keep track of the window's geometry so there is something to save.
+    subscription::events_with(|event, _status| match event {
+      Event::Window(window::Event::Resized { width, height }) => Some(Message::Resized(width, height)),
+      Event::Window(window::Event::Moved { x, y }) => Some(Message::Moved(x, y)),
+      Event::Window(window::Event::CloseRequested) => Some(Message::CloseRequested),
+      _ => None,
+    })
+  }
+}
@@ -0,0 +1,18 @@
+#!/bin/sh
+# Prints a line every half second, with every third line going to stderr.
+# Takes the number of lines to print as its only argument.
+
+count=${1:-20}
+
+i=1
+while [ "$i" -le "$count" ]; do
+  if [ $((i % 3)) -eq 0 ]; then
+    echo "line $i (stderr)" >&2
+  else
+    echo "line $i"
+  fi
+  i=$((i + 1))
+  sleep 0.5
+done
+
+exit 3
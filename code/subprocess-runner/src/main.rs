@@ -0,0 +1,217 @@
+mod process;
+
+use iced::widget::scrollable::{self, RelativeOffset};
+use iced::widget::{Button, Column, Container, Row, Scrollable, Text, TextInput};
+use iced::{executor, Alignment, Application, Color, Command, Element, Font, Length, Settings, Subscription, Theme};
+
+pub fn main() -> iced::Result {
+  SubprocessRunner::run(Settings::default())
+}
+
+const PRESETS: [&str; 3] = ["ls -la", "cargo --version", "sh scripts/count.sh 20"];
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  CommandChanged(String),
+  Preset(&'static str),
+  Run,
+  Cancel,
+  Process((usize, process::Event)),
+}
+
+enum Status {
+  Idle,
+  Running,
+  Exited(Option<i32>),
+  Cancelled,
+  FailedToStart(String),
+}
+
+struct Line {
+  text: String,
+  is_stderr: bool,
+}
+
+struct SubprocessRunner {
+  command: String,
+  /// Incremented for every run, so each run gets its own Subscription.
+  run_id: usize,
+  status: Status,
+  /// Set while a run's Subscription should be kept alive.
+  running: Option<(String, Vec<String>)>,
+  lines: Vec<Line>,
+  log_id: scrollable::Id,
+}
+
+impl Application for SubprocessRunner {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      SubprocessRunner {
+        command: String::from(PRESETS[2]),
+        run_id: 0,
+        status: Status::Idle,
+        running: None,
+        lines: Vec::new(),
+        log_id: scrollable::Id::unique(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Subprocess Runner")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::CommandChanged(command) => self.command = command,
+      Message::Preset(command) => self.command = String::from(command),
+      Message::Run => {
+        let mut words = self.command.split_whitespace().map(String::from);
+
+        if let Some(program) = words.next() {
+          self.run_id += 1;
+          self.running = Some((program, words.collect()));
+          self.lines.clear();
+          self.status = Status::Running;
+        }
+      }
+      Message::Cancel => {
+        // Dropping the Subscription kills the process.
+        self.running = None;
+        self.status = Status::Cancelled;
+      }
+      Message::Process((run_id, event)) => {
+        // Output of a cancelled run can still be on its way.
+        if run_id != self.run_id || self.running.is_none() {
+          return Command::none();
+        }
+
+        match event {
+          process::Event::Started => {}
+          process::Event::Stdout(text) => self.lines.push(Line {
+            text,
+            is_stderr: false,
+          }),
+          process::Event::Stderr(text) => self.lines.push(Line {
+            text,
+            is_stderr: true,
+          }),
+          process::Event::Exited(code) => {
+            self.running = None;
+            self.status = Status::Exited(code);
+          }
+          process::Event::FailedToStart(error) => {
+            self.running = None;
+            self.status = Status::FailedToStart(error);
+          }
+        }
+
+        return scrollable::snap_to(self.log_id.clone(), RelativeOffset { x: 0.0, y: 1.0 });
+      }
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let is_running = self.running.is_some();
+
+    let presets = Row::with_children(
+      PRESETS
+        .into_iter()
+        .map(|preset| {
+          Button::new(Text::new(preset))
+            .on_press_maybe((!is_running).then_some(Message::Preset(preset)))
+            .into()
+        })
+        .collect(),
+    )
+    .spacing(10);
+
+    let mut command = TextInput::new("Command", &self.command)
+      .padding(10)
+      .font(Font::MONOSPACE);
+
+    if !is_running {
+      command = command
+        .on_input(Message::CommandChanged)
+        .on_submit(Message::Run);
+    }
+
+    let controls = Row::with_children(vec![
+      command.into(),
+      Button::new("Run")
+        .on_press_maybe((!is_running).then_some(Message::Run))
+        .padding(10)
+        .into(),
+      Button::new("Cancel")
+        .on_press_maybe(is_running.then_some(Message::Cancel))
+        .padding(10)
+        .into(),
+    ])
+    .spacing(10)
+    .align_items(Alignment::Center);
+
+    let status = match &self.status {
+      Status::Idle => String::from("Not started"),
+      Status::Running => String::from("Running..."),
+      Status::Exited(Some(code)) => format!("Exited with code {}", code),
+      Status::Exited(None) => String::from("Terminated by a signal"),
+      Status::Cancelled => String::from("Killed"),
+      Status::FailedToStart(error) => format!("Failed to start: {}", error),
+    };
+
+    // stderr is shown in red.
+    let log = Scrollable::new(
+      Column::with_children(
+        self
+          .lines
+          .iter()
+          .map(|line| {
+            let text = Text::new(line.text.as_str()).font(Font::MONOSPACE).size(14);
+
+            if line.is_stderr {
+              text.style(Color::from_rgb(0.8, 0.2, 0.2)).into()
+            } else {
+              text.into()
+            }
+          })
+          .collect(),
+      )
+      .width(Length::Fill),
+    )
+    .id(self.log_id.clone())
+    .height(Length::Fill);
+
+    Container::new(
+      Column::with_children(vec![
+        presets.into(),
+        controls.into(),
+        Text::new(status).into(),
+        log.into(),
+      ])
+      .spacing(20),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(20)
+    .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    match &self.running {
+      Some((program, args)) => process::run(self.run_id, program.clone(), args.clone()).map(Message::Process),
+      None => Subscription::none(),
+    }
+  }
+}
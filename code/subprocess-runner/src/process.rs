@@ -0,0 +1,85 @@
+use iced::futures::SinkExt;
+use iced::{subscription, Subscription};
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+#[derive(Debug, Clone)]
+pub enum Event {
+  Started,
+  Stdout(String),
+  Stderr(String),
+  /// The exit code. This is None if the process was terminated by a signal.
+  Exited(Option<i32>),
+  FailedToStart(String),
+}
+
+/// Runs a process and streams its output line by line.
+///
+/// Every run needs a new id, otherwise Iced would keep the previous Subscription. When the Subscription is dropped, the
+/// process is killed. That is how a run is cancelled.
+pub fn run(id: usize, program: String, args: Vec<String>) -> Subscription<(usize, Event)> {
+  subscription::channel(id, 100, move |mut output| async move {
+    let child = tokio::process::Command::new(&program)
+      .args(&args)
+      .stdin(Stdio::null())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .kill_on_drop(true)
+      .spawn();
+
+    match child {
+      Ok(mut child) => {
+        let _ = output.send((id, Event::Started)).await;
+
+        let mut stdout = child
+          .stdout
+          .take()
+          .map(|stdout| BufReader::new(stdout).lines());
+        let mut stderr = child
+          .stderr
+          .take()
+          .map(|stderr| BufReader::new(stderr).lines());
+
+        // Read both pipes at the same time so neither can fill up and block the process.
+        while stdout.is_some() || stderr.is_some() {
+          tokio::select! {
+            line = next_line(&mut stdout), if stdout.is_some() => match line {
+              Some(line) => {
+                let _ = output.send((id, Event::Stdout(line))).await;
+              }
+              None => stdout = None,
+            },
+            line = next_line(&mut stderr), if stderr.is_some() => match line {
+              Some(line) => {
+                let _ = output.send((id, Event::Stderr(line))).await;
+              }
+              None => stderr = None,
+            },
+          }
+        }
+
+        let code = child.wait().await.ok().and_then(|status| status.code());
+        let _ = output.send((id, Event::Exited(code))).await;
+      }
+      Err(error) => {
+        let _ = output
+          .send((id, Event::FailedToStart(error.to_string())))
+          .await;
+      }
+    }
+
+    // The future given to channel must never return. The Application drops the Subscription once it sees the result.
+    iced::futures::future::pending().await
+  })
+}
+
+/// Reads the next line of a pipe. Returns None once the pipe is closed or unreadable.
+async fn next_line<R>(lines: &mut Option<tokio::io::Lines<BufReader<R>>>) -> Option<String>
+where
+  R: tokio::io::AsyncRead + Unpin,
+{
+  match lines {
+    Some(lines) => lines.next_line().await.ok().flatten(),
+    None => None,
+  }
+}
@@ -0,0 +1,155 @@
+/// A change to some state that knows how to undo itself.
+///
+/// This is the command pattern: every edit carries enough information to produce its inverse.
+pub trait Edit<State> {
+  fn apply(&self, state: &mut State);
+
+  fn revert(&self, state: &mut State);
+
+  /// A short, human readable label shown in the history panel.
+  fn describe(&self) -> String;
+}
+
+/// An entry in the history.
+///
+/// Small, targeted changes are best stored as an `Edit`. Changes that are hard to invert (i.e. clearing many items at once)
+/// can store a `Snapshot` of the whole state instead, trading memory for simplicity.
+pub enum Change<State, E> {
+  Edit(E),
+  Snapshot {
+    description: String,
+    before: State,
+    after: State,
+  },
+}
+
+impl<State, E> Change<State, E>
+where
+  State: Clone,
+  E: Edit<State>,
+{
+  fn apply(&self, state: &mut State) {
+    match self {
+      Change::Edit(edit) => edit.apply(state),
+      Change::Snapshot { after, .. } => *state = after.clone(),
+    }
+  }
+
+  fn revert(&self, state: &mut State) {
+    match self {
+      Change::Edit(edit) => edit.revert(state),
+      Change::Snapshot { before, .. } => *state = before.clone(),
+    }
+  }
+
+  pub fn describe(&self) -> String {
+    match self {
+      Change::Edit(edit) => edit.describe(),
+      Change::Snapshot { description, .. } => description.clone(),
+    }
+  }
+}
+
+/// An undo/redo stack.
+///
+/// The history does not own the state. Every method takes the state it should modify so it can live next to any other
+/// field of an Application.
+pub struct History<State, E> {
+  undo_stack: Vec<Change<State, E>>,
+  redo_stack: Vec<Change<State, E>>,
+  limit: Option<usize>,
+}
+
+impl<State, E> History<State, E>
+where
+  State: Clone,
+  E: Edit<State>,
+{
+  pub fn new() -> Self {
+    History {
+      undo_stack: Vec::new(),
+      redo_stack: Vec::new(),
+      limit: None,
+    }
+  }
+
+  /// Creates a history that forgets the oldest changes once more than `limit` are stored.
+  pub fn with_limit(limit: usize) -> Self {
+    History {
+      limit: Some(limit),
+      ..History::new()
+    }
+  }
+
+  /// Applies an edit to the state and records it.
+  pub fn apply(&mut self, state: &mut State, edit: E) {
+    edit.apply(state);
+    self.record(Change::Edit(edit));
+  }
+
+  /// Runs `f` on the state and records a snapshot of the state before and after it.
+  pub fn snapshot(&mut self, state: &mut State, description: impl Into<String>, f: impl FnOnce(&mut State)) {
+    let before = state.clone();
+    f(state);
+
+    self.record(Change::Snapshot {
+      description: description.into(),
+      before,
+      after: state.clone(),
+    });
+  }
+
+  /// Reverts the most recent change. Returns false if there was nothing to undo.
+  pub fn undo(&mut self, state: &mut State) -> bool {
+    match self.undo_stack.pop() {
+      Some(change) => {
+        change.revert(state);
+        self.redo_stack.push(change);
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Re-applies the most recently undone change. Returns false if there was nothing to redo.
+  pub fn redo(&mut self, state: &mut State) -> bool {
+    match self.redo_stack.pop() {
+      Some(change) => {
+        change.apply(state);
+        self.undo_stack.push(change);
+        true
+      }
+      None => false,
+    }
+  }
+
+  pub fn can_undo(&self) -> bool {
+    !self.undo_stack.is_empty()
+  }
+
+  pub fn can_redo(&self) -> bool {
+    !self.redo_stack.is_empty()
+  }
+
+  /// Changes that can be undone, oldest first.
+  pub fn undo_stack(&self) -> &[Change<State, E>] {
+    &self.undo_stack
+  }
+
+  /// Changes that can be redone, oldest first. The last one will be redone next.
+  pub fn redo_stack(&self) -> &[Change<State, E>] {
+    &self.redo_stack
+  }
+
+  fn record(&mut self, change: Change<State, E>) {
+    // A new change invalidates everything that was undone.
+    self.redo_stack.clear();
+    self.undo_stack.push(change);
+
+    if let Some(limit) = self.limit {
+      if self.undo_stack.len() > limit {
+        self.undo_stack.remove(0);
+      }
+    }
+  }
+}
@@ -0,0 +1,249 @@
+mod history;
+
+use history::{Edit, History};
+use iced::keyboard::KeyCode;
+use iced::widget::{Button, Checkbox, Column, Container, Row, Rule, Scrollable, Text, TextInput};
+use iced::{event, executor, keyboard, subscription, Alignment, Application, Color, Command, Element, Event, Length, Settings, Subscription, Theme};
+
+pub fn main() -> iced::Result {
+  UndoRedo::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  NewTodoChanged(String),
+  AddTodo,
+  ToggleTodo(usize),
+  RemoveTodo(usize),
+  ClearCompleted,
+  Undo,
+  Redo,
+}
+
+#[derive(Debug, Clone)]
+pub struct Todo {
+  text: String,
+  done: bool,
+}
+
+/// The part of our state that is tracked by the history.
+#[derive(Debug, Clone, Default)]
+pub struct Todos {
+  items: Vec<Todo>,
+}
+
+/// Every edit stores what it needs to be reverted.
+#[derive(Debug, Clone)]
+pub enum TodoEdit {
+  Add { text: String },
+  Toggle { index: usize },
+  Remove { index: usize, todo: Todo },
+}
+
+impl Edit<Todos> for TodoEdit {
+  fn apply(&self, state: &mut Todos) {
+    match self {
+      TodoEdit::Add { text } => state.items.push(Todo {
+        text: text.clone(),
+        done: false,
+      }),
+      TodoEdit::Toggle { index } => state.items[*index].done = !state.items[*index].done,
+      TodoEdit::Remove { index, .. } => {
+        state.items.remove(*index);
+      }
+    }
+  }
+
+  fn revert(&self, state: &mut Todos) {
+    match self {
+      TodoEdit::Add { .. } => {
+        state.items.pop();
+      }
+      // Toggling is its own inverse.
+      TodoEdit::Toggle { .. } => self.apply(state),
+      TodoEdit::Remove { index, todo } => state.items.insert(*index, todo.clone()),
+    }
+  }
+
+  fn describe(&self) -> String {
+    match self {
+      TodoEdit::Add { text } => format!("Add \"{}\"", text),
+      TodoEdit::Toggle { index } => format!("Toggle #{}", index + 1),
+      TodoEdit::Remove { todo, .. } => format!("Remove \"{}\"", todo.text),
+    }
+  }
+}
+
+struct UndoRedo {
+  todos: Todos,
+  history: History<Todos, TodoEdit>,
+  new_todo: String,
+}
+
+impl Application for UndoRedo {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      UndoRedo {
+        todos: Todos::default(),
+        history: History::with_limit(100),
+        new_todo: String::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("Undo Redo")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::NewTodoChanged(new_todo) => self.new_todo = new_todo,
+      Message::AddTodo => {
+        if !self.new_todo.is_empty() {
+          let text = std::mem::take(&mut self.new_todo);
+          self.history.apply(&mut self.todos, TodoEdit::Add { text });
+        }
+      }
+      Message::ToggleTodo(index) => self
+        .history
+        .apply(&mut self.todos, TodoEdit::Toggle { index }),
+      Message::RemoveTodo(index) => {
+        let todo = self.todos.items[index].clone();
+        self
+          .history
+          .apply(&mut self.todos, TodoEdit::Remove { index, todo });
+      }
+      Message::ClearCompleted => {
+        // Removing many items at once is easier to undo with a snapshot than with an inverse edit.
+        self
+          .history
+          .snapshot(&mut self.todos, "Clear completed", |todos| {
+            todos.items.retain(|todo| !todo.done)
+          });
+      }
+      Message::Undo => {
+        self.history.undo(&mut self.todos);
+      }
+      Message::Redo => {
+        self.history.redo(&mut self.todos);
+      }
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let new_todo = TextInput::new("What needs to be done?", &self.new_todo)
+      .on_input(Message::NewTodoChanged)
+      .on_submit(Message::AddTodo)
+      .padding(10);
+
+    let todos = self
+      .todos
+      .items
+      .iter()
+      .enumerate()
+      .map(|(index, todo)| {
+        Row::with_children(vec![
+          Checkbox::new(todo.text.as_str(), todo.done, move |_| {
+            Message::ToggleTodo(index)
+          })
+          .width(Length::Fill)
+          .into(),
+          Button::new("Remove")
+            .on_press(Message::RemoveTodo(index))
+            .into(),
+        ])
+        .spacing(20)
+        .align_items(Alignment::Center)
+        .into()
+      })
+      .collect();
+
+    let has_completed = self.todos.items.iter().any(|todo| todo.done);
+
+    let controls = Row::with_children(vec![
+      Button::new("Undo")
+        .on_press_maybe(self.history.can_undo().then_some(Message::Undo))
+        .into(),
+      Button::new("Redo")
+        .on_press_maybe(self.history.can_redo().then_some(Message::Redo))
+        .into(),
+      Button::new("Clear completed")
+        .on_press_maybe(has_completed.then_some(Message::ClearCompleted))
+        .into(),
+    ])
+    .spacing(10);
+
+    let editor = Column::with_children(vec![
+      new_todo.into(),
+      controls.into(),
+      Scrollable::new(Column::with_children(todos).spacing(10)).into(),
+    ])
+    .spacing(20)
+    .width(Length::Fill);
+
+    // Undone changes are shown greyed out below the ones that can still be undone.
+    let mut history = vec![Text::new("History").size(24).into()];
+    history.extend(
+      self
+        .history
+        .undo_stack()
+        .iter()
+        .map(|change| Text::new(change.describe()).into()),
+    );
+    history.extend(self.history.redo_stack().iter().rev().map(|change| {
+      Text::new(change.describe())
+        .style(Color::from_rgb(0.6, 0.6, 0.6))
+        .into()
+    }));
+
+    let history = Scrollable::new(Column::with_children(history).spacing(5)).width(250);
+
+    let row = Row::with_children(vec![
+      editor.into(),
+      Rule::vertical(10).into(),
+      history.into(),
+    ])
+    .spacing(20);
+
+    Container::new(row)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    subscription::events_with(keyboard_shortcut)
+  }
+}
+
+/// Maps Ctrl+Z to undo and Ctrl+Shift+Z to redo.
+///
+/// `command()` is Ctrl on Windows and Linux and Cmd on macOS.
+fn keyboard_shortcut(event: Event, _status: event::Status) -> Option<Message> {
+  match event {
+    Event::Keyboard(keyboard::Event::KeyPressed {
+      key_code: KeyCode::Z,
+      modifiers,
+    }) if modifiers.command() => {
+      if modifiers.shift() {
+        Some(Message::Redo)
+      } else {
+        Some(Message::Undo)
+      }
+    }
+    _ => None,
+  }
+}
@@ -0,0 +1,85 @@
+//! A tiny chat server so the client can run without internet access.
+//!
+//! Every message a client sends is broadcast to every connected client, including the sender.
+//! Clients send "<id> <text>". The copy that goes back to the sender starts with "#<id> " to confirm it.
+//! Run it with `cargo run --bin server`.
+
+use futures::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+
+const ADDRESS: &str = "127.0.0.1:3030";
+
+#[derive(Debug, Clone)]
+struct Broadcast {
+  from: SocketAddr,
+  /// The id the sender gave the message.
+  id: String,
+  text: String,
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+  let listener = TcpListener::bind(ADDRESS).await?;
+  let (sender, _) = broadcast::channel::<Broadcast>(100);
+
+  println!("Listening on ws://{}", ADDRESS);
+
+  loop {
+    let (stream, peer) = listener.accept().await?;
+    tokio::spawn(handle_connection(stream, peer, sender.clone()));
+  }
+}
+
+async fn handle_connection(stream: TcpStream, peer: SocketAddr, sender: broadcast::Sender<Broadcast>) {
+  let websocket = match tokio_tungstenite::accept_async(stream).await {
+    Ok(websocket) => websocket,
+    Err(error) => {
+      eprintln!("{} failed to connect: {}", peer, error);
+      return;
+    }
+  };
+
+  println!("{} connected", peer);
+
+  let (mut outgoing, mut incoming) = websocket.split();
+  let mut receiver = sender.subscribe();
+
+  loop {
+    tokio::select! {
+      message = incoming.next() => match message {
+        Some(Ok(Message::Text(message))) => {
+          if let Some((id, text)) = message.split_once(' ') {
+            let _ = sender.send(Broadcast {
+              from: peer,
+              id: id.to_string(),
+              text: format!("{}: {}", peer, text),
+            });
+          }
+        }
+        Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+        Some(Ok(_)) => {}
+      },
+      broadcast = receiver.recv() => match broadcast {
+        Ok(broadcast) => {
+          let text = if broadcast.from == peer {
+            format!("#{} {}", broadcast.id, broadcast.text)
+          } else {
+            broadcast.text
+          };
+
+          if outgoing.send(Message::Text(text)).await.is_err() {
+            break;
+          }
+        }
+        // A slow client missed some messages. Keep going with the newest ones.
+        Err(broadcast::error::RecvError::Lagged(_)) => {}
+        Err(broadcast::error::RecvError::Closed) => break,
+      }
+    }
+  }
+
+  println!("{} disconnected", peer);
+}
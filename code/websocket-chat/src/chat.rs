@@ -0,0 +1,110 @@
+use iced::futures::channel::mpsc;
+use iced::futures::{self, SinkExt, StreamExt};
+use iced::{subscription, Subscription};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+pub const SERVER_URL: &str = "ws://127.0.0.1:3030";
+
+// Messages go to the server as "<id> <text>". The server broadcasts them to everyone, and the copy that goes back to
+// the sender starts with "#<id> ", so the sender knows the server has it.
+
+#[derive(Debug, Clone)]
+pub enum Event {
+  Connected(Connection),
+  Disconnected,
+  /// The server got the message with this id: its broadcast came back to us.
+  Confirmed(u64),
+  MessageReceived(String),
+}
+
+/// A handle to an open connection.
+#[derive(Debug, Clone)]
+pub struct Connection(mpsc::Sender<(u64, String)>);
+
+impl Connection {
+  /// Hands a message to the connection. Returns false once the connection is gone or its buffer is full.
+  ///
+  /// A message that was handed over can still be lost if the connection drops before it reaches the server, only
+  /// Confirmed says that it got there.
+  pub fn send(&mut self, id: u64, message: String) -> bool {
+    self.0.try_send((id, message)).is_ok()
+  }
+}
+
+enum State {
+  Disconnected,
+  Connected(
+    WebSocketStream<MaybeTlsStream<TcpStream>>,
+    mpsc::Receiver<(u64, String)>,
+  ),
+}
+
+/// Keeps a connection to the chat server open, reconnecting every second while the server is unreachable.
+pub fn connect() -> Subscription<Event> {
+  struct Connect;
+
+  subscription::channel(
+    std::any::TypeId::of::<Connect>(),
+    100,
+    |mut output| async move {
+      let mut state = State::Disconnected;
+
+      loop {
+        match &mut state {
+          State::Disconnected => match tokio_tungstenite::connect_async(SERVER_URL).await {
+            Ok((websocket, _)) => {
+              let (sender, receiver) = mpsc::channel(100);
+              let _ = output.send(Event::Connected(Connection(sender))).await;
+              state = State::Connected(websocket, receiver);
+            }
+            Err(_) => {
+              tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+              let _ = output.send(Event::Disconnected).await;
+            }
+          },
+          State::Connected(websocket, input) => {
+            let mut fused_websocket = websocket.by_ref().fuse();
+
+            futures::select! {
+              received = fused_websocket.select_next_some() => match received {
+                Ok(tungstenite::Message::Text(message)) => {
+                  let message = match parse_echo(&message) {
+                    Some((id, text)) => {
+                      let _ = output.send(Event::Confirmed(id)).await;
+                      text.to_string()
+                    }
+                    None => message,
+                  };
+
+                  let _ = output.send(Event::MessageReceived(message)).await;
+                }
+                Ok(tungstenite::Message::Close(_)) | Err(_) => {
+                  let _ = output.send(Event::Disconnected).await;
+                  state = State::Disconnected;
+                }
+                Ok(_) => continue,
+              },
+              (id, message) = input.select_next_some() => {
+                let result = websocket.send(tungstenite::Message::Text(format!("{} {}", id, message))).await;
+
+                if result.is_err() {
+                  let _ = output.send(Event::Disconnected).await;
+                  state = State::Disconnected;
+                }
+              }
+            }
+          }
+        }
+      }
+    },
+  )
+}
+
+/// Splits a message the server sent back to us into the id we gave it and the text to show.
+fn parse_echo(message: &str) -> Option<(u64, &str)> {
+  let (id, text) = message.strip_prefix('#')?.split_once(' ')?;
+
+  Some((id.parse().ok()?, text))
+}
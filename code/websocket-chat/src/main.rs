@@ -0,0 +1,234 @@
+mod chat;
+
+use iced::widget::scrollable::{self, RelativeOffset};
+use iced::widget::{Button, Column, Container, Row, Scrollable, Text, TextInput};
+use iced::{executor, Alignment, Application, Color, Command, Element, Length, Settings, Subscription, Theme};
+use std::collections::VecDeque;
+
+pub fn main() -> iced::Result {
+  WebSocketChat::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Chat(chat::Event),
+  DraftChanged(String),
+  Send,
+  ToggleConnection,
+}
+
+enum ConnectionState {
+  Connecting,
+  Connected(chat::Connection),
+  /// The server could not be reached. The Subscription keeps retrying.
+  Reconnecting,
+  /// The user turned the connection off.
+  Offline,
+}
+
+/// A message the server hasn't confirmed yet.
+struct Outgoing {
+  id: u64,
+  text: String,
+}
+
+struct WebSocketChat {
+  connection: ConnectionState,
+  messages: Vec<String>,
+  /// Every message that was written but not confirmed, in order. A message only leaves it once the server has
+  /// broadcast it back (chat::Event::Confirmed), so a connection that drops with messages in flight doesn't lose them.
+  /// If it drops after the server got one but before the echo arrived, that one is sent twice.
+  outbox: VecDeque<Outgoing>,
+  /// How many messages at the front of the outbox were handed to the current connection.
+  handed_over: usize,
+  next_id: u64,
+  draft: String,
+  messages_id: scrollable::Id,
+}
+
+impl WebSocketChat {
+  /// Hands the messages the current connection doesn't have yet to it, until the outbox runs out or the connection
+  /// can't take more.
+  fn flush_outbox(&mut self) {
+    let ConnectionState::Connected(connection) = &mut self.connection else {
+      return;
+    };
+
+    for outgoing in self.outbox.iter().skip(self.handed_over) {
+      if !connection.send(outgoing.id, outgoing.text.clone()) {
+        break;
+      }
+
+      self.handed_over += 1;
+    }
+  }
+}
+
+impl Application for WebSocketChat {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      WebSocketChat {
+        connection: ConnectionState::Connecting,
+        messages: Vec::new(),
+        outbox: VecDeque::new(),
+        handed_over: 0,
+        next_id: 0,
+        draft: String::new(),
+        messages_id: scrollable::Id::unique(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("WebSocket Chat")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Chat(event) => match event {
+        // A new connection has none of the messages, so everything unconfirmed is sent again.
+        chat::Event::Connected(connection) => {
+          self.connection = ConnectionState::Connected(connection);
+          self.handed_over = 0;
+          self.flush_outbox();
+        }
+        chat::Event::Disconnected => {
+          if let ConnectionState::Offline = self.connection {
+            return Command::none();
+          }
+
+          self.connection = ConnectionState::Reconnecting;
+        }
+        chat::Event::Confirmed(id) => {
+          if let Some(index) = self.outbox.iter().position(|outgoing| outgoing.id == id) {
+            self.outbox.remove(index);
+            self.handed_over = self.handed_over.saturating_sub(1);
+          }
+
+          // The connection has room again for anything that didn't fit before.
+          self.flush_outbox();
+        }
+        chat::Event::MessageReceived(message) => {
+          self.messages.push(message);
+
+          // Keep the newest message in view.
+          return scrollable::snap_to(self.messages_id.clone(), RelativeOffset { x: 0.0, y: 1.0 });
+        }
+      },
+      Message::DraftChanged(draft) => self.draft = draft,
+      Message::Send => {
+        if !self.draft.is_empty() {
+          self.outbox.push_back(Outgoing {
+            id: self.next_id,
+            text: std::mem::take(&mut self.draft),
+          });
+          self.next_id += 1;
+          self.flush_outbox();
+        }
+      }
+      Message::ToggleConnection => {
+        // Dropping the Subscription closes the connection. Returning it again reconnects.
+        self.connection = match self.connection {
+          ConnectionState::Offline => ConnectionState::Connecting,
+          _ => ConnectionState::Offline,
+        };
+      }
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let (status, color) = match self.connection {
+      ConnectionState::Connecting => (
+        String::from("Connecting..."),
+        Color::from_rgb(0.8, 0.6, 0.0),
+      ),
+      ConnectionState::Connected(_) => (
+        format!("Connected to {}", chat::SERVER_URL),
+        Color::from_rgb(0.2, 0.6, 0.2),
+      ),
+      ConnectionState::Reconnecting => (
+        format!(
+          "Can't reach {}, retrying... (start it with `cargo run --bin server`)",
+          chat::SERVER_URL
+        ),
+        Color::from_rgb(0.8, 0.2, 0.2),
+      ),
+      ConnectionState::Offline => (String::from("Offline"), Color::from_rgb(0.5, 0.5, 0.5)),
+    };
+
+    let toggle_label = if let ConnectionState::Offline = self.connection {
+      "Connect"
+    } else {
+      "Disconnect"
+    };
+
+    let header = Row::with_children(vec![
+      Text::new(status).style(color).width(Length::Fill).into(),
+      Button::new(toggle_label)
+        .on_press(Message::ToggleConnection)
+        .into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center);
+
+    let mut messages: Vec<Element<Message>> = self
+      .messages
+      .iter()
+      .map(|message| Text::new(message.as_str()).into())
+      .collect();
+
+    // Queued messages are shown greyed out until the server confirms them.
+    messages.extend(self.outbox.iter().map(|outgoing| {
+      Text::new(format!("(queued) {}", outgoing.text))
+        .style(Color::from_rgb(0.6, 0.6, 0.6))
+        .into()
+    }));
+
+    let messages = Scrollable::new(
+      Column::with_children(messages)
+        .spacing(5)
+        .width(Length::Fill),
+    )
+    .id(self.messages_id.clone())
+    .height(Length::Fill);
+
+    let input = Row::with_children(vec![
+      TextInput::new("Type a message...", &self.draft)
+        .on_input(Message::DraftChanged)
+        .on_submit(Message::Send)
+        .padding(10)
+        .into(),
+      Button::new("Send")
+        .on_press_maybe((!self.draft.is_empty()).then_some(Message::Send))
+        .padding(10)
+        .into(),
+    ])
+    .spacing(10);
+
+    Container::new(Column::with_children(vec![header.into(), messages.into(), input.into()]).spacing(20))
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    if let ConnectionState::Offline = self.connection {
+      return Subscription::none();
+    }
+
+    chat::connect().map(Message::Chat)
+  }
+}
@@ -0,0 +1,275 @@
+use iced::widget::{Button, Column, Container, Row, Rule, Text, TextInput, Toggler};
+use iced::{executor, subscription, window, Alignment, Application, Command, Element, Event, Length, Settings, Size, Subscription, Theme};
+use std::time::Duration;
+
+// Every setting is spelled out here, even the ones left at their defaults, so this doubles as a reference.
+//
+// This is a single window. Multiple windows sharing one state are in the multi-window example, which needs a newer
+// version of Iced for multi_window::Application.
+pub fn main() -> iced::Result {
+  WindowSettings::run(Settings {
+    window: window::Settings {
+      size: (700, 600),
+      position: window::Position::Centered,
+      min_size: Some((500, 400)),
+      max_size: Some((1600, 1200)),
+      visible: true,
+      resizable: true,
+      decorations: true,
+      transparent: false,
+      level: window::Level::Normal,
+      icon: Some(icon()),
+      ..window::Settings::default()
+    },
+    ..Settings::default()
+  })
+}
+
+/// A 32x32 blue circle, generated so the example doesn't need an image file.
+fn icon() -> window::Icon {
+  const SIZE: u32 = 32;
+
+  let mut rgba = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+
+  for y in 0..SIZE {
+    for x in 0..SIZE {
+      let dx = x as f32 - SIZE as f32 / 2.0;
+      let dy = y as f32 - SIZE as f32 / 2.0;
+      let alpha = if dx * dx + dy * dy <= (SIZE as f32 / 2.0).powi(2) {
+        255
+      } else {
+        0
+      };
+
+      rgba.extend([40, 110, 220, alpha]);
+    }
+  }
+
+  window::icon::from_rgba(rgba, SIZE, SIZE).expect("The icon has exactly SIZE * SIZE pixels")
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Resized(u32, u32),
+  Moved(i32, i32),
+  WidthChanged(String),
+  HeightChanged(String),
+  XChanged(String),
+  YChanged(String),
+  Resize,
+  Move,
+  Maximize(bool),
+  Minimize,
+  ToggleFullscreen,
+  ModeFetched(window::Mode),
+  ToggleDecorations,
+  AlwaysOnTop(bool),
+  Hide,
+  Show,
+  Close,
+}
+
+struct WindowSettings {
+  /// The window's size and position, as last reported by Iced.
+  size: (u32, u32),
+  position: Option<(i32, i32)>,
+  width: String,
+  height: String,
+  x: String,
+  y: String,
+  is_maximized: bool,
+  is_always_on_top: bool,
+}
+
+impl Application for WindowSettings {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      WindowSettings {
+        size: (700, 600),
+        position: None,
+        width: String::from("900"),
+        height: String::from("700"),
+        x: String::from("100"),
+        y: String::from("100"),
+        is_maximized: false,
+        is_always_on_top: false,
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    format!("Window Settings - {}x{}", self.size.0, self.size.1)
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Resized(width, height) => self.size = (width, height),
+      Message::Moved(x, y) => self.position = Some((x, y)),
+      Message::WidthChanged(width) => self.width = width,
+      Message::HeightChanged(height) => self.height = height,
+      Message::XChanged(x) => self.x = x,
+      Message::YChanged(y) => self.y = y,
+      Message::Resize => {
+        if let (Ok(width), Ok(height)) = (self.width.parse(), self.height.parse()) {
+          // The window manager still enforces min_size and max_size.
+          return window::resize(Size::new(width, height));
+        }
+      }
+      Message::Move => {
+        if let (Ok(x), Ok(y)) = (self.x.parse(), self.y.parse()) {
+          return window::move_to(x, y);
+        }
+      }
+      Message::Maximize(is_maximized) => {
+        self.is_maximized = is_maximized;
+        return window::maximize(is_maximized);
+      }
+      Message::Minimize => return window::minimize(true),
+      // Ask for the current mode first. The user can also leave fullscreen without us.
+      Message::ToggleFullscreen => return window::fetch_mode(Message::ModeFetched),
+      Message::ModeFetched(mode) => {
+        let mode = match mode {
+          window::Mode::Fullscreen => window::Mode::Windowed,
+          _ => window::Mode::Fullscreen,
+        };
+
+        return window::change_mode(mode);
+      }
+      Message::ToggleDecorations => return window::toggle_decorations(),
+      Message::AlwaysOnTop(is_always_on_top) => {
+        self.is_always_on_top = is_always_on_top;
+
+        let level = if is_always_on_top {
+          window::Level::AlwaysOnTop
+        } else {
+          window::Level::Normal
+        };
+
+        return window::change_level(level);
+      }
+      Message::Hide => {
+        // A hidden window can't be clicked, so it has to come back on its own.
+        return Command::batch(vec![
+          window::change_mode(window::Mode::Hidden),
+          Command::perform(tokio::time::sleep(Duration::from_secs(2)), |_| {
+            Message::Show
+          }),
+        ]);
+      }
+      Message::Show => return window::change_mode(window::Mode::Windowed),
+      Message::Close => return window::close(),
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let position = match self.position {
+      Some((x, y)) => format!("{}, {}", x, y),
+      None => String::from("not moved yet"),
+    };
+
+    let resize = Row::with_children(vec![
+      TextInput::new("Width", &self.width)
+        .on_input(Message::WidthChanged)
+        .on_submit(Message::Resize)
+        .width(100)
+        .into(),
+      Text::new("x").into(),
+      TextInput::new("Height", &self.height)
+        .on_input(Message::HeightChanged)
+        .on_submit(Message::Resize)
+        .width(100)
+        .into(),
+      Button::new("Resize").on_press(Message::Resize).into(),
+    ])
+    .spacing(10)
+    .align_items(Alignment::Center);
+
+    let move_to = Row::with_children(vec![
+      TextInput::new("X", &self.x)
+        .on_input(Message::XChanged)
+        .on_submit(Message::Move)
+        .width(100)
+        .into(),
+      Text::new(",").into(),
+      TextInput::new("Y", &self.y)
+        .on_input(Message::YChanged)
+        .on_submit(Message::Move)
+        .width(100)
+        .into(),
+      Button::new("Move").on_press(Message::Move).into(),
+    ])
+    .spacing(10)
+    .align_items(Alignment::Center);
+
+    let modes = Row::with_children(vec![
+      Button::new("Toggle fullscreen")
+        .on_press(Message::ToggleFullscreen)
+        .into(),
+      Button::new("Minimize").on_press(Message::Minimize).into(),
+      Button::new("Toggle decorations")
+        .on_press(Message::ToggleDecorations)
+        .into(),
+      Button::new("Hide for 2 seconds")
+        .on_press(Message::Hide)
+        .into(),
+    ])
+    .spacing(10);
+
+    let toggles = Row::with_children(vec![
+      Toggler::new(
+        String::from("Maximized"),
+        self.is_maximized,
+        Message::Maximize,
+      )
+      .width(Length::Shrink)
+      .into(),
+      Toggler::new(
+        String::from("Always on top"),
+        self.is_always_on_top,
+        Message::AlwaysOnTop,
+      )
+      .width(Length::Shrink)
+      .into(),
+    ])
+    .spacing(20);
+
+    Container::new(
+      Column::with_children(vec![
+        Text::new(format!("Size: {}x{}", self.size.0, self.size.1)).into(),
+        Text::new(format!("Position: {}", position)).into(),
+        Rule::horizontal(10).into(),
+        resize.into(),
+        move_to.into(),
+        modes.into(),
+        toggles.into(),
+        Rule::horizontal(10).into(),
+        Button::new("Close").on_press(Message::Close).into(),
+      ])
+      .spacing(20),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(20)
+    .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    subscription::events_with(|event, _status| match event {
+      Event::Window(window::Event::Resized { width, height }) => Some(Message::Resized(width, height)),
+      Event::Window(window::Event::Moved { x, y }) => Some(Message::Moved(x, y)),
+      _ => None,
+    })
+  }
+}
@@ -0,0 +1,207 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono_tz::Tz;
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{combo_box, Button, Column, ComboBox, Container, Row, Rule, Scrollable, Text, TextInput, Toggler};
+use iced::{executor, Alignment, Application, Command, Element, Length, Settings, Subscription, Theme};
+
+extern crate chrono;
+
+pub fn main() -> iced::Result {
+  WorldClock::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+  Tick,
+  AddTimeZone(Tz),
+  RemoveClock(usize),
+  ToggleTwelveHour(bool),
+  FormatChanged(String),
+}
+
+struct Clock {
+  id: usize,
+  time_zone: Tz,
+}
+
+struct WorldClock {
+  current_time: chrono::DateTime<chrono::Utc>,
+  clocks: Vec<Clock>,
+  next_clock_id: usize,
+  time_zones: combo_box::State<Tz>,
+  is_twelve_hour: bool,
+  custom_format: String,
+}
+
+impl WorldClock {
+  /// The format used to display every clock.
+  ///
+  /// A custom format wins as long as it is valid, otherwise we fall back to the 12/24h default.
+  fn format(&self) -> &str {
+    if !self.custom_format.is_empty() && is_valid_format(&self.custom_format) {
+      return &self.custom_format;
+    }
+
+    if self.is_twelve_hour {
+      "%Y-%m-%d %I:%M:%S %p"
+    } else {
+      "%Y-%m-%d %H:%M:%S"
+    }
+  }
+}
+
+impl Application for WorldClock {
+  type Executor = executor::Default;
+  type Message = Message;
+  type Theme = Theme;
+  type Flags = ();
+
+  fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    (
+      WorldClock {
+        current_time: chrono::Utc::now(),
+        clocks: vec![
+          Clock {
+            id: 0,
+            time_zone: chrono_tz::UTC,
+          },
+          Clock {
+            id: 1,
+            time_zone: chrono_tz::America::New_York,
+          },
+        ],
+        next_clock_id: 2,
+        time_zones: combo_box::State::new(chrono_tz::TZ_VARIANTS.to_vec()),
+        is_twelve_hour: false,
+        custom_format: String::new(),
+      },
+      Command::none(),
+    )
+  }
+
+  fn title(&self) -> String {
+    String::from("World Clock")
+  }
+
+  fn update(&mut self, message: Message) -> Command<Self::Message> {
+    match message {
+      Message::Tick => {
+        self.current_time = chrono::Utc::now();
+      }
+      Message::AddTimeZone(time_zone) => {
+        self.clocks.push(Clock {
+          id: self.next_clock_id,
+          time_zone,
+        });
+        self.next_clock_id += 1;
+        self.time_zones.unfocus();
+        // There are no ticks while there are no clocks, so the last time may be long out of date.
+        self.current_time = chrono::Utc::now();
+      }
+      Message::RemoveClock(id) => {
+        self.clocks.retain(|clock| clock.id != id);
+      }
+      Message::ToggleTwelveHour(is_twelve_hour) => self.is_twelve_hour = is_twelve_hour,
+      Message::FormatChanged(custom_format) => self.custom_format = custom_format,
+    }
+
+    Command::none()
+  }
+
+  fn view(&self) -> Element<Message> {
+    let format = self.format();
+
+    // Every clock is rendered from the same shared timestamp, only the time zone differs.
+    let clocks = self
+      .clocks
+      .iter()
+      .map(|clock| {
+        let local_time = self.current_time.with_timezone(&clock.time_zone);
+
+        Row::with_children(vec![
+          Text::new(clock.time_zone.name()).width(250).into(),
+          Text::new(local_time.format(format).to_string())
+            .width(Length::Fill)
+            .into(),
+          Text::new(local_time.format("UTC%:z").to_string())
+            .width(100)
+            .into(),
+          Button::new("Remove")
+            .on_press(Message::RemoveClock(clock.id))
+            .into(),
+        ])
+        .spacing(20)
+        .align_items(Alignment::Center)
+        .into()
+      })
+      .collect();
+
+    let add_clock = ComboBox::new(
+      &self.time_zones,
+      "Search for a time zone...",
+      None,
+      Message::AddTimeZone,
+    )
+    .width(300);
+
+    let twelve_hour = Toggler::new(
+      String::from("12-hour clock"),
+      self.is_twelve_hour,
+      Message::ToggleTwelveHour,
+    )
+    .width(Length::Shrink);
+
+    let custom_format = TextInput::new("Custom format e.g. %H:%M", &self.custom_format)
+      .on_input(Message::FormatChanged)
+      .width(300);
+
+    let format_status = if self.custom_format.is_empty() || is_valid_format(&self.custom_format) {
+      Text::new(format!("Using \"{}\"", format))
+    } else {
+      Text::new("Invalid format, using the default.")
+    };
+
+    let controls = Row::with_children(vec![
+      add_clock.into(),
+      twelve_hour.into(),
+      custom_format.into(),
+      format_status.into(),
+    ])
+    .spacing(20)
+    .align_items(Alignment::Center);
+
+    let column = Column::with_children(vec![
+      controls.into(),
+      Rule::horizontal(10).into(),
+      Scrollable::new(Column::with_children(clocks).spacing(10)).into(),
+    ])
+    .spacing(20)
+    .max_width(1000);
+
+    Container::new(column)
+      .align_x(Horizontal::Center)
+      .align_y(Vertical::Top)
+      .width(Length::Fill)
+      .height(Length::Fill)
+      .padding(20)
+      .into()
+  }
+
+  fn theme(&self) -> Theme {
+    Theme::default()
+  }
+
+  fn subscription(&self) -> Subscription<Self::Message> {
+    if self.clocks.is_empty() {
+      return Subscription::none();
+    }
+
+    // One timer drives every clock.
+    iced::time::every(std::time::Duration::from_millis(500)).map(|_| Message::Tick)
+  }
+}
+
+/// chrono reports bad specifiers as an error while formatting, so we check them up front.
+fn is_valid_format(format: &str) -> bool {
+  !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}